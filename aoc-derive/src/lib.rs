@@ -0,0 +1,360 @@
+//!
+//! # aoc-derive
+//! The `FromInput` derive of the `aoc` crate, generating the `FromStr` of the types of the
+//! inputs from a pattern.
+//!
+//! ```ignore
+//! #[derive(FromInput)]
+//! #[input("{start}-{end}")]
+//! struct Range {
+//!     start: u32,
+//!     end: u32,
+//! }
+//!
+//! #[derive(FromInput)]
+//! enum Instruction {
+//!     #[input("noop")]
+//!     Noop,
+//!     #[input("addx {0}")]
+//!     Addx(i32),
+//! }
+//! ```
+//!
+//! The patterns are the templates of `aoc::scan!`, with the name (or the index, for a tuple) of
+//! a field in each placeholder; `{}` takes the next index. The fields implement
+//! `aoc::parse::Field`, like the numbers, `String`, `char` and the other derived types. The
+//! attributes of a field change how it is read:
+//!
+//! * `#[input(sep = ", ")]` - A `Vec` of the items separated by the text
+//! * `#[input(default)]` - A field missing from the pattern, set to its `Default`
+//!
+//! A generic type requires its type parameters to be fields too, like `Pair<T>` of two `T`.
+//!
+//! The variants of an enum are tried in order. When none matches, the error of the one that
+//! read the furthest is returned.
+//!
+
+// Imports  ==============================================================================  Imports
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr,
+    Member,
+};
+
+// Variables  =========================================================================== Variables
+///
+/// # Pattern
+///
+/// A pattern parsed from its `#[input("...")]` attribute.
+struct Pattern {
+    /// The pattern as written, for the error messages.
+    text: String,
+    /// The template given to `aoc::parse::captures`, with a `{}` for each placeholder.
+    template: String,
+    /// The field of each placeholder, in order.
+    members: Vec<Member>,
+    /// The attribute, to report the problems of the pattern.
+    span: Span,
+}
+
+///
+/// # FieldOptions
+///
+/// The options of a field, from its `#[input(...)]` attribute.
+#[derive(Default)]
+struct FieldOptions {
+    separator: Option<LitStr>,
+    default: bool,
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # derive_from_input
+///
+/// Implement `FromStr` and `aoc::parse::Field` for a struct or an enum, from the
+/// `#[input("...")]` pattern of the struct or of each variant.
+#[proc_macro_derive(FromInput, attributes(input))]
+pub fn derive_from_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+///
+/// # expand
+///
+/// ## Arguments
+///
+/// * `input` - The derived type
+///
+/// ## Returns
+///
+/// * `syn::Result<TokenStream2>` - The implementations, or the problem with the type
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    // Every type parameter is read like a field, from text of any lifetime
+    let mut generics = input.generics.clone();
+    let parameters: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for parameter in parameters {
+        where_clause
+            .predicates
+            .push(parse_quote!(#parameter: for<'field> ::aoc::parse::Field<'field>));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // The `Field` implementation also has the lifetime of the text
+    let mut field_generics = generics.clone();
+    field_generics.params.insert(0, parse_quote!('input));
+    let (field_impl_generics, _, _) = field_generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern(&input.attrs, input.ident.span())?;
+            let constructor = constructor(quote!(#name), &data.fields, &pattern)?;
+            let template = &pattern.template;
+
+            quote! {
+                let captures = ::aoc::parse::captures(s, #template)?;
+                Ok(#constructor)
+            }
+        }
+        Data::Enum(data) => {
+            let mut attempts = Vec::new();
+            let mut texts = Vec::new();
+
+            for variant in &data.variants {
+                let pattern = pattern(&variant.attrs, variant.ident.span())?;
+                let variant_name = &variant.ident;
+                let constructor =
+                    constructor(quote!(#name::#variant_name), &variant.fields, &pattern)?;
+                let template = &pattern.template;
+
+                attempts.push(quote! {
+                    let attempt = || -> ::std::result::Result<Self, ::aoc::error::AocError> {
+                        let captures = ::aoc::parse::captures(s, #template)?;
+                        Ok(#constructor)
+                    };
+                    match attempt() {
+                        Ok(value) => return Ok(value),
+                        Err(error) => errors.push(error),
+                    }
+                });
+                texts.push(pattern.text);
+            }
+
+            quote! {
+                let mut errors = ::std::vec::Vec::new();
+                #(#attempts)*
+                Err(::aoc::parse::furthest(errors, &[#(#texts),*]))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.ident.span(),
+                "FromInput can't be derived for a union",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #type_generics #where_clause {
+            type Err = ::aoc::error::AocError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                #body
+            }
+        }
+
+        impl #field_impl_generics ::aoc::parse::Field<'input> for #name #type_generics #where_clause {
+            fn field(token: &'input str) -> ::std::result::Result<Self, ::aoc::error::AocError> {
+                token.parse()
+            }
+        }
+    })
+}
+
+///
+/// # pattern
+///
+/// ## Arguments
+///
+/// * `attrs` - The attributes of the struct or of the variant
+/// * `span` - Where to report a missing pattern
+///
+/// ## Returns
+///
+/// * `syn::Result<Pattern>` - The pattern of the `#[input("...")]` attribute
+fn pattern(attrs: &[Attribute], span: Span) -> syn::Result<Pattern> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("input"))
+        .ok_or_else(|| Error::new(span, "missing the pattern, like `#[input(\"{a}-{b}\")]`"))?;
+    let literal: LitStr = attr.parse_args()?;
+    let text = literal.value();
+
+    let mut template = String::new();
+    let mut members = Vec::new();
+    let mut rest = text.as_str();
+
+    while let Some(open) = rest.find(['{', '}']) {
+        if rest[open..].starts_with('}') {
+            return Err(Error::new(literal.span(), "unmatched `}` in the pattern"));
+        }
+
+        let close = rest[open..]
+            .find('}')
+            .map(|close| open + close)
+            .ok_or_else(|| Error::new(literal.span(), "unmatched `{` in the pattern"))?;
+        let name = rest[open + 1..close].trim();
+
+        template.push_str(&rest[..open]);
+        template.push_str("{}");
+        if !members.is_empty() && rest[..open].is_empty() {
+            return Err(Error::new(
+                literal.span(),
+                "two placeholders must be separated by some text",
+            ));
+        }
+
+        let member = if name.is_empty() {
+            Member::from(members.len())
+        } else if let Ok(index) = name.parse::<usize>() {
+            Member::from(index)
+        } else {
+            syn::parse_str::<Member>(name).map_err(|_| {
+                Error::new(
+                    literal.span(),
+                    format!("`{}` isn't the name of a field", name),
+                )
+            })?
+        };
+        members.push(member);
+        rest = &rest[close + 1..];
+    }
+    template.push_str(rest);
+
+    Ok(Pattern {
+        text,
+        template,
+        members,
+        span: literal.span(),
+    })
+}
+
+///
+/// # field_options
+///
+/// ## Arguments
+///
+/// * `attrs` - The attributes of a field
+///
+/// ## Returns
+///
+/// * `syn::Result<FieldOptions>` - The options of its `#[input(...)]` attribute
+fn field_options(attrs: &[Attribute]) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("input")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("sep") {
+                options.separator = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                options.default = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `sep = \"...\"` or `default`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+///
+/// # constructor
+///
+/// ## Arguments
+///
+/// * `path` - The path of the struct or of the variant
+/// * `fields` - Its fields
+/// * `pattern` - Its pattern
+///
+/// ## Returns
+///
+/// * `syn::Result<TokenStream2>` - The expression building the value from the `captures` of the
+///   pattern, or an error if the fields and the placeholders don't match
+fn constructor(
+    path: TokenStream2,
+    fields: &Fields,
+    pattern: &Pattern,
+) -> syn::Result<TokenStream2> {
+    let mut values = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::from(ident.clone()),
+            None => Member::from(index),
+        };
+        let options = field_options(&field.attrs)?;
+        let positions: Vec<usize> = pattern
+            .members
+            .iter()
+            .enumerate()
+            .filter(|(_, placeholder)| **placeholder == member)
+            .map(|(position, _)| position)
+            .collect();
+
+        let value = match (positions.as_slice(), options.default, &options.separator) {
+            ([], true, _) => quote!(::std::default::Default::default()),
+            ([], false, _) => {
+                return Err(Error::new(
+                    field.span(),
+                    "this field isn't in the pattern, add it or mark it `#[input(default)]`",
+                ))
+            }
+            ([position], _, Some(separator)) => {
+                quote!(::aoc::parse::list(s, captures[#position], #separator)?)
+            }
+            ([position], _, None) => quote!(::aoc::parse::field(s, captures[#position])?),
+            _ => {
+                return Err(Error::new(
+                    field.span(),
+                    "this field is in the pattern more than once",
+                ))
+            }
+        };
+        values.push(quote!(#member: #value));
+    }
+
+    if let Some(unknown) = pattern.members.iter().find(|placeholder| {
+        !fields
+            .iter()
+            .enumerate()
+            .any(|(index, field)| match &field.ident {
+                Some(ident) => **placeholder == Member::from(ident.clone()),
+                None => **placeholder == Member::from(index),
+            })
+    }) {
+        let name = match unknown {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        return Err(Error::new(
+            pattern.span,
+            format!("the pattern `{}` has no field `{}`", pattern.text, name),
+        ));
+    }
+
+    Ok(quote!(#path { #(#values),* }))
+}
@@ -0,0 +1,114 @@
+//! Generates the table of inputs compiled into the binary when the `embed-inputs` feature is
+//! enabled. Without the feature the table is empty and inputs are only read at runtime.
+//!
+//! Also generates one test per example of `data/year_XXXX/examples`, included by
+//! `tests/examples.rs`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let repo_data_dir = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("data");
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", repo_data_dir.display());
+
+    embedded_inputs(&out_dir, &repo_data_dir);
+    example_tests(&out_dir, &repo_data_dir);
+}
+
+/// Writes `embedded_inputs.rs`, the inputs compiled into the binary.
+fn embedded_inputs(out_dir: &Path, repo_data_dir: &Path) {
+    let mut table = String::from("pub static EMBEDDED_INPUTS: &[(u16, u8, &str)] = &[\n");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+
+        let data_dir = match env::var_os("AOC_DATA_DIR") {
+            Some(data_dir) if !data_dir.is_empty() => PathBuf::from(data_dir),
+            _ => repo_data_dir.to_path_buf(),
+        };
+        println!("cargo:rerun-if-changed={}", data_dir.display());
+
+        for (year, name, path) in find_days(&data_dir, "inputs") {
+            let Ok(day) = name.parse::<u8>() else {
+                continue;
+            };
+
+            println!("cargo:rerun-if-changed={}", path.display());
+            table.push_str(&format!(
+                "    ({}, {}, include_str!({:?})),\n",
+                year,
+                day,
+                path.canonicalize().unwrap()
+            ));
+        }
+    }
+
+    table.push_str("];\n");
+    fs::write(out_dir.join("embedded_inputs.rs"), table).unwrap();
+}
+
+/// Writes `example_tests.rs`, one test per `day_XX_N.txt` example of the repository.
+fn example_tests(out_dir: &Path, repo_data_dir: &Path) {
+    let mut tests = String::new();
+
+    for (year, name, _) in find_days(repo_data_dir, "examples") {
+        let Some((day, index)) = name
+            .split_once('_')
+            .and_then(|(day, index)| Some((day.parse::<u8>().ok()?, index.parse::<u8>().ok()?)))
+        else {
+            continue;
+        };
+
+        tests.push_str(&format!(
+            "#[test]\nfn year_{}_day_{:02}_{}() {{\n    check_example({}, {}, {});\n}}\n\n",
+            year, day, index, year, day, index
+        ));
+    }
+
+    fs::write(out_dir.join("example_tests.rs"), tests).unwrap();
+}
+
+/// Every `year_XXXX/<folder>/day_*.txt` file of the data directory, with its year and the
+/// part of its name after `day_`.
+fn find_days(data_dir: &Path, folder: &str) -> Vec<(u16, String, PathBuf)> {
+    let mut files = Vec::new();
+
+    let Ok(years) = fs::read_dir(data_dir) else {
+        return files;
+    };
+
+    for year_entry in years.flatten() {
+        let year_name = year_entry.file_name();
+        let Some(year) = year_name
+            .to_str()
+            .and_then(|name| name.strip_prefix("year_"))
+            .and_then(|year| year.parse::<u16>().ok())
+        else {
+            continue;
+        };
+
+        let Ok(days) = fs::read_dir(year_entry.path().join(folder)) else {
+            continue;
+        };
+
+        for day_entry in days.flatten() {
+            let day_name = day_entry.file_name();
+            let Some(name) = day_name
+                .to_str()
+                .and_then(|name| name.strip_prefix("day_"))
+                .and_then(|name| name.strip_suffix(".txt"))
+            else {
+                continue;
+            };
+
+            files.push((year, name.to_string(), day_entry.path()));
+        }
+    }
+
+    files.sort();
+    files
+}
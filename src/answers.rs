@@ -0,0 +1,370 @@
+//!
+//! # answers
+//! The store of the known correct answers, used to check that a refactor didn't break a day.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::solution::{Answer, Part};
+
+// Variables  =========================================================================== Variables
+/// Name of the answers file, inside the data directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+///
+/// # AnswersError
+///
+/// Error returned when the answers file can't be loaded.
+#[derive(Debug)]
+pub enum AnswersError {
+    /// The file exists but couldn't be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The file isn't valid TOML.
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The file is valid TOML, but an entry isn't an answer.
+    Invalid { path: PathBuf, key: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => {
+                write!(f, "can't read {}: {}", path.display(), source)
+            }
+            AnswersError::Toml { path, source } => {
+                write!(f, "invalid answers file {}: {}", path.display(), source)
+            }
+            AnswersError::Invalid { path, key } => write!(
+                f,
+                "invalid answers file {}: `{}` should be a `year_XXXX.day_XX.part_N` number or string",
+                path.display(),
+                key
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io { source, .. } => Some(source),
+            AnswersError::Toml { source, .. } => Some(source),
+            AnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+///
+/// # Verdict
+///
+/// The result of comparing an answer with the stored one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the stored one.
+    Pass,
+    /// The answer differs from the stored one.
+    Fail { expected: String },
+    /// There is no stored answer for this part.
+    Missing,
+}
+
+///
+/// # AnswerStore
+///
+/// The known correct answers, read from a TOML file keyed by year, day and part:
+///
+/// ```toml
+/// [year_2022.day_05]
+/// part_1 = "CMZ"
+/// part_2 = "MCD"
+///
+/// [year_2022.day_16]
+/// part_1 = 1651
+/// ```
+///
+/// Numbers too large for a TOML integer can be written as strings.
+#[derive(Debug, Clone, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u16, u8, Part), String>,
+}
+
+impl AnswerStore {
+    ///
+    /// # path
+    ///
+    /// ## Arguments
+    ///
+    /// * `data_dir` - The data directory
+    ///
+    /// ## Returns
+    ///
+    /// * `PathBuf` - Where the answers file is expected
+    pub fn path(data_dir: &Path) -> PathBuf {
+        data_dir.join(ANSWERS_FILE)
+    }
+
+    ///
+    /// # load
+    ///
+    /// Load the answers file. A missing file is an empty store, every answer will be missing.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The answers file
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<AnswerStore, AnswersError>` - The stored answers
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(content) => AnswerStore::parse(&content, path),
+            Err(source) if source.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(source) => Err(AnswersError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    ///
+    /// # parse
+    ///
+    /// ## Arguments
+    ///
+    /// * `content` - The content of the answers file
+    /// * `path` - The answers file, for error messages
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<AnswerStore, AnswersError>` - The stored answers
+    pub fn parse(content: &str, path: &Path) -> Result<Self, AnswersError> {
+        let table: toml::Table = content.parse().map_err(|source| AnswersError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let invalid = |key: String| AnswersError::Invalid {
+            path: path.to_path_buf(),
+            key,
+        };
+
+        let mut answers = BTreeMap::new();
+        for (year_key, days) in &table {
+            let year = parse_key(year_key, "year_").ok_or_else(|| invalid(year_key.clone()))?;
+            let days = days.as_table().ok_or_else(|| invalid(year_key.clone()))?;
+
+            for (day_key, parts) in days {
+                let key = format!("{}.{}", year_key, day_key);
+                let day = parse_key(day_key, "day_").ok_or_else(|| invalid(key.clone()))?;
+
+                for (part, answer) in parse_parts(parts, &key).map_err(invalid)? {
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+
+        Ok(AnswerStore { answers })
+    }
+
+    ///
+    /// # get
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `part` - The part of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&str>` - The stored answer, if there is one
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    ///
+    /// # check
+    ///
+    /// Compare an answer with the stored one, see [`matches`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `part` - The part of the puzzle
+    /// * `answer` - The answer to check
+    ///
+    /// ## Returns
+    ///
+    /// * `Verdict` - Whether the answer is the stored one
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Missing,
+            Some(expected) if matches(answer, expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+///
+/// # matches
+///
+/// Compare an answer with an expected one, through its display so `1651` and `"1651"` are
+/// the same answer. An unsolved part never matches.
+///
+/// ## Arguments
+///
+/// * `answer` - The answer to check
+/// * `expected` - The expected answer
+///
+/// ## Returns
+///
+/// * `bool` - Whether the answer is the expected one
+pub fn matches(answer: &Answer, expected: &str) -> bool {
+    *answer != Answer::Unsolved && answer.to_string() == expected
+}
+
+///
+/// # parse_parts
+///
+/// Parse a table of answers, like `{ part_1 = 1651, part_2 = "MCD" }`.
+///
+/// ## Arguments
+///
+/// * `parts` - The TOML table
+/// * `key` - The key of the table, to report an invalid entry
+///
+/// ## Returns
+///
+/// * `Result<Vec<(Part, String)>, String>` - The answers, or the key of the invalid entry
+pub(crate) fn parse_parts(parts: &toml::Value, key: &str) -> Result<Vec<(Part, String)>, String> {
+    let parts = parts.as_table().ok_or_else(|| key.to_string())?;
+
+    parts
+        .iter()
+        .map(|(part_key, value)| {
+            let key = format!("{}.{}", key, part_key);
+            let part = part_key
+                .strip_prefix("part_")
+                .and_then(|part| part.parse::<Part>().ok())
+                .ok_or_else(|| key.clone())?;
+            let answer = match value {
+                toml::Value::Integer(n) => n.to_string(),
+                toml::Value::String(s) => s.clone(),
+                _ => return Err(key),
+            };
+
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+///
+/// # parse_key
+///
+/// ## Arguments
+///
+/// * `key` - A key like `year_2022` or `day_05`
+/// * `prefix` - The expected prefix
+///
+/// ## Returns
+///
+/// * `Option<T>` - The number after the prefix
+fn parse_key<T: std::str::FromStr>(key: &str, prefix: &str) -> Option<T> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[year_2022.day_05]
+part_1 = "CMZ"
+part_2 = "MCD"
+
+[year_2022.day_16]
+part_1 = 1651
+part_2 = "1707"
+"#;
+
+    fn store() -> AnswerStore {
+        AnswerStore::parse(ANSWERS, Path::new("answers.toml")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let store = store();
+
+        assert_eq!(store.get(2022, 5, Part::One), Some("CMZ"));
+        assert_eq!(store.get(2022, 16, Part::One), Some("1651"));
+        assert_eq!(store.get(2022, 16, Part::Two), Some("1707"));
+        assert_eq!(store.get(2022, 6, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let path = Path::new("answers.toml");
+
+        assert!(matches!(
+            AnswerStore::parse("[year_2022.day_05]\npart_3 = 1", path),
+            Err(AnswersError::Invalid { key, .. }) if key == "year_2022.day_05.part_3"
+        ));
+        assert!(matches!(
+            AnswerStore::parse("[year_2022.day_05]\npart_1 = 1.5", path),
+            Err(AnswersError::Invalid { .. })
+        ));
+        assert!(matches!(
+            AnswerStore::parse("[2022]\nday_05 = 1", path),
+            Err(AnswersError::Invalid { .. })
+        ));
+        assert!(matches!(
+            AnswerStore::parse("year_2022 = [", path),
+            Err(AnswersError::Toml { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check() {
+        let store = store();
+
+        assert_eq!(
+            store.check(2022, 16, Part::One, &Answer::Number(1651)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            store.check(2022, 16, Part::Two, &Answer::Number(1707)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            store.check(2022, 5, Part::One, &Answer::from("CMZ")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            store.check(2022, 5, Part::Two, &Answer::from("CMZ")),
+            Verdict::Fail {
+                expected: "MCD".to_string()
+            }
+        );
+        assert_eq!(
+            store.check(2022, 16, Part::Two, &Answer::Unsolved),
+            Verdict::Fail {
+                expected: "1707".to_string()
+            }
+        );
+        assert_eq!(
+            store.check(2022, 6, Part::One, &Answer::Number(7)),
+            Verdict::Missing
+        );
+    }
+}
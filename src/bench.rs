@@ -0,0 +1,325 @@
+//!
+//! # bench
+//! Timing of the parsing and of both parts of a puzzle, to find the slow solutions.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+use crate::registry::Puzzle;
+use crate::solution::{Answer, Part};
+
+// Variables  =========================================================================== Variables
+///
+/// # BenchOptions
+///
+/// How many times each step is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Runs before measuring, to warm up the caches and the allocator.
+    pub warmup: usize,
+    /// Measured runs.
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+///
+/// # Stats
+///
+/// Statistics over the measured runs of a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    ///
+    /// # from_samples
+    ///
+    /// ## Arguments
+    ///
+    /// * `samples` - The duration of every measured run
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Stats>` - The statistics, or `None` without samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = samples.len() as f64;
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Some(Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            samples: samples.len(),
+        })
+    }
+}
+
+///
+/// # PuzzleBench
+///
+/// The timings of a puzzle. A part is `None` when it isn't implemented.
+#[derive(Debug, Clone)]
+pub struct PuzzleBench {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # measure
+///
+/// Run a function `options.warmup` times, then time it `options.iterations` times.
+///
+/// ## Arguments
+///
+/// * `options` - How many times the function is run
+/// * `f` - The function to time
+///
+/// ## Returns
+///
+/// * `Stats` - The statistics of the measured runs
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples).expect("there is at least one iteration")
+}
+
+///
+/// # bench
+///
+/// Time the parsing and both parts of a puzzle separately.
+/// The parts are timed on an input parsed once, outside of the measure.
+///
+/// ## Arguments
+///
+/// * `puzzle` - The puzzle to time
+/// * `input` - Its raw input
+/// * `options` - How many times each step is run
+///
+/// ## Returns
+///
+/// * `Result<PuzzleBench, AocError>` - The timings of the puzzle, or why its input is invalid
+pub fn bench(
+    puzzle: &Puzzle,
+    input: &str,
+    options: &BenchOptions,
+) -> Result<PuzzleBench, AocError> {
+    let parsed = puzzle.parse(input)?;
+    let parse = measure(options, || puzzle.parse(black_box(input)));
+
+    let part = |part: Part| {
+        if puzzle.solve_parsed(parsed.as_ref(), part) == Answer::Unsolved {
+            return None;
+        }
+
+        Some(measure(options, || {
+            puzzle.solve_parsed(black_box(parsed.as_ref()), part)
+        }))
+    };
+
+    Ok(PuzzleBench {
+        year: puzzle.year,
+        day: puzzle.day,
+        parse,
+        part_one: part(Part::One),
+        part_two: part(Part::Two),
+    })
+}
+
+///
+/// # format_duration
+///
+/// ## Arguments
+///
+/// * `duration` - The duration to display
+///
+/// ## Returns
+///
+/// * `String` - The duration with 3 significant digits, in the most readable unit
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+
+    let decimals = if unit == "ns" || value >= 100.0 {
+        0
+    } else if value < 10.0 {
+        2
+    } else {
+        1
+    };
+
+    format!("{:.*} {}", decimals, value, unit)
+}
+
+///
+/// # format_stats
+///
+/// ## Arguments
+///
+/// * `stats` - The statistics of a step, `None` if the step isn't implemented
+///
+/// ## Returns
+///
+/// * `String` - `mean ± stddev (median)`, or `-`
+pub fn format_stats(stats: Option<&Stats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "{} ± {} (median {})",
+            format_duration(stats.mean),
+            format_duration(stats.stddev),
+            format_duration(stats.median)
+        ),
+        None => "-".to_string(),
+    }
+}
+
+///
+/// # markdown_table
+///
+/// ## Arguments
+///
+/// * `benches` - The timings of every puzzle
+///
+/// ## Returns
+///
+/// * `String` - A Markdown table with one row per puzzle
+pub fn markdown_table(benches: &[PuzzleBench]) -> String {
+    let mut table = String::from("| Year | Day | Parse | Part 1 | Part 2 |\n");
+    table.push_str("|------|-----|-------|--------|--------|\n");
+
+    for bench in benches {
+        table.push_str(&format!(
+            "| {} | {:02} | {} | {} | {} |\n",
+            bench.year,
+            bench.day,
+            format_stats(Some(&bench.parse)),
+            format_stats(bench.part_one.as_ref()),
+            format_stats(bench.part_two.as_ref())
+        ));
+    }
+
+    table
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&millis(&[2, 4, 4, 4, 5, 5, 7, 9])).unwrap();
+
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(
+            stats.median,
+            Duration::from_millis(4) + Duration::from_micros(500)
+        );
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+        assert_eq!(stats.samples, 8);
+
+        let stats = Stats::from_samples(&millis(&[3, 1, 2])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(2));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_iterations() {
+        let mut runs = 0;
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 5,
+        };
+
+        let stats = measure(&options, || runs += 1);
+
+        assert_eq!(runs, 7);
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.23 µs");
+        assert_eq!(format_duration(Duration::from_micros(45_600)), "45.6 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50 s");
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let stats = Stats::from_samples(&millis(&[1])).unwrap();
+        let table = markdown_table(&[PuzzleBench {
+            year: 2022,
+            day: 16,
+            parse: stats,
+            part_one: Some(stats),
+            part_two: None,
+        }]);
+
+        assert_eq!(
+            table,
+            "| Year | Day | Parse | Part 1 | Part 2 |\n\
+             |------|-----|-------|--------|--------|\n\
+             | 2022 | 16 | 1.00 ms ± 0 ns (median 1.00 ms) | 1.00 ms ± 0 ns (median 1.00 ms) | - |\n"
+        );
+    }
+}
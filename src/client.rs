@@ -0,0 +1,443 @@
+//!
+//! # client
+//! Access to the Advent of Code website, to download the inputs with `aoc fetch` and send
+//! the answers with `aoc submit`.
+//!
+//! The requests are authenticated with the session cookie of the website, read from the
+//! `AOC_SESSION` environment variable or from the configuration file. Every downloaded input
+//! is cached in the data directory and never requested again.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::input::InputLoader;
+
+// Variables  =========================================================================== Variables
+/// Environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable overriding the address of the website, to use a stand-in server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable overriding the path of the configuration file.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// The address of the website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// How long a request may take before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+///
+/// # ClientError
+///
+/// Error returned when the website can't be reached or refuses a request.
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie is configured.
+    MissingSession,
+    /// The configuration file exists but is invalid.
+    Config { path: PathBuf, message: String },
+    /// The website answered with an error status.
+    Status { url: String, status: u16 },
+    /// The website couldn't be reached.
+    Transport { url: String, message: String },
+    /// The configuration file couldn't be read, or a cached input couldn't be written.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie, set {} or `session` in the configuration file",
+                SESSION_ENV
+            ),
+            ClientError::Config { path, message } => {
+                write!(f, "invalid configuration {}: {}", path.display(), message)
+            }
+            ClientError::Status { url, status } => {
+                let reason = match status {
+                    400 => ", the session cookie is probably invalid or expired",
+                    404 => ", the puzzle is probably not unlocked yet",
+                    _ => "",
+                };
+                write!(f, "{} answered {}{}", url, status, reason)
+            }
+            ClientError::Transport { url, message } => {
+                write!(f, "can't reach {}: {}", url, message)
+            }
+            ClientError::Io { path, source } => {
+                write!(f, "can't access {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+///
+/// # ClientConfig
+///
+/// The settings of the client, read from a TOML file:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// contact = "me@example.com"
+/// ```
+///
+/// The environment variables `AOC_SESSION` and `AOC_BASE_URL` take precedence over the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// The address of the website, without a trailing slash.
+    pub base_url: String,
+    /// The value of the `session` cookie of the website.
+    pub session: Option<String>,
+    /// How to reach the author of the requests, added to the User-Agent.
+    pub contact: Option<String>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            contact: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    ///
+    /// # path
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<PathBuf>` - The configuration file: `$AOC_CONFIG`, or `aoc/config.toml` in
+    ///   `$XDG_CONFIG_HOME` or `~/.config`
+    pub fn path() -> Option<PathBuf> {
+        let non_empty = |name: &str| env::var_os(name).filter(|value| !value.is_empty());
+
+        if let Some(path) = non_empty(CONFIG_ENV) {
+            return Some(PathBuf::from(path));
+        }
+
+        let config_dir = non_empty("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(config_dir.join("aoc").join("config.toml"))
+    }
+
+    ///
+    /// # parse
+    ///
+    /// ## Arguments
+    ///
+    /// * `content` - The content of a configuration file
+    /// * `path` - Its path, for error messages
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<ClientConfig, ClientError>` - The configuration, with defaults for the missing
+    ///   keys
+    pub fn parse(content: &str, path: &Path) -> Result<Self, ClientError> {
+        let invalid = |message: String| ClientError::Config {
+            path: path.to_path_buf(),
+            message,
+        };
+        let table: toml::Table = content.parse().map_err(|e| invalid(format!("{}", e)))?;
+        let string = |key: &str| -> Result<Option<String>, ClientError> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(toml::Value::String(value)) => Ok(Some(value.trim().to_string())),
+                Some(_) => Err(invalid(format!("`{}` must be a string", key))),
+            }
+        };
+
+        let mut config = ClientConfig {
+            session: string("session")?,
+            contact: string("contact")?,
+            ..ClientConfig::default()
+        };
+        if let Some(base_url) = string("base_url")? {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    ///
+    /// # load
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<ClientConfig, ClientError>` - The configuration file if there is one, overridden
+    ///   by the environment
+    pub fn load() -> Result<Self, ClientError> {
+        let mut config = match ClientConfig::path() {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(content) => ClientConfig::parse(&content, &path)?,
+                Err(source) if source.kind() == io::ErrorKind::NotFound => ClientConfig::default(),
+                Err(source) => return Err(ClientError::Io { path, source }),
+            },
+            None => ClientConfig::default(),
+        };
+
+        if let Some(session) = env::var(SESSION_ENV).ok().filter(|s| !s.trim().is_empty()) {
+            config.session = Some(session.trim().to_string());
+        }
+        if let Some(base_url) = env::var(BASE_URL_ENV).ok().filter(|url| !url.is_empty()) {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+}
+
+///
+/// # Client
+///
+/// An HTTP client of the website, identifying itself with a descriptive User-Agent.
+pub struct Client {
+    config: ClientConfig,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `config` - The address of the website and the session cookie
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent(config.contact.as_deref()))
+            .timeout(TIMEOUT)
+            .build();
+
+        Client { config, agent }
+    }
+
+    ///
+    /// # url
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - A path of the website, starting with `/`
+    ///
+    /// ## Returns
+    ///
+    /// * `String` - Its full address
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    ///
+    /// # session_cookie
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<String, ClientError>` - The `Cookie` header of the requests
+    fn session_cookie(&self) -> Result<String, ClientError> {
+        let session = self
+            .config
+            .session
+            .as_deref()
+            .ok_or(ClientError::MissingSession)?;
+
+        Ok(format!("session={}", session))
+    }
+
+    ///
+    /// # get
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The path of the page, starting with `/`
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<String, ClientError>` - The body of the page
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.session_cookie()?)
+            .call();
+
+        read_response(url, response)
+    }
+
+    ///
+    /// # post_form
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The path of the form, starting with `/`
+    /// * `fields` - The names and values of the fields, url-encoded when sent
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<String, ClientError>` - The body of the page answering the form
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.session_cookie()?)
+            .send_form(fields);
+
+        read_response(url, response)
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # user_agent
+///
+/// ## Arguments
+///
+/// * `contact` - How to reach the author of the requests
+///
+/// ## Returns
+///
+/// * `String` - The User-Agent of the requests, naming this tool and its version
+pub fn user_agent(contact: Option<&str>) -> String {
+    let tool = format!(
+        "{}/{} (Advent of Code solutions in Rust",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    match contact {
+        Some(contact) => format!("{}; contact: {})", tool, contact),
+        None => format!("{})", tool),
+    }
+}
+
+///
+/// # read_response
+///
+/// ## Arguments
+///
+/// * `url` - The address requested
+/// * `response` - What the request returned
+///
+/// ## Returns
+///
+/// * `Result<String, ClientError>` - The body of a successful response
+fn read_response(
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|error| ClientError::Transport {
+                url,
+                message: error.to_string(),
+            }),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status { url, status }),
+        Err(ureq::Error::Transport(error)) => {
+            // The message of ureq starts with the url already
+            let message = error.to_string();
+            let message = message
+                .trim_start_matches(&format!("{}: ", url))
+                .to_string();
+            Err(ClientError::Transport { url, message })
+        }
+    }
+}
+
+///
+/// # fetch_input
+///
+/// Download the input of a puzzle into the data directory, unless it is already there.
+///
+/// ## Arguments
+///
+/// * `client` - The client of the website
+/// * `loader` - Where the inputs are stored
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+///
+/// ## Returns
+///
+/// * `Result<(PathBuf, bool), ClientError>` - The path of the input, and whether it was
+///   downloaded (`false` when it was already cached)
+pub fn fetch_input(
+    client: &Client,
+    loader: &InputLoader,
+    year: u16,
+    day: u8,
+) -> Result<(PathBuf, bool), ClientError> {
+    let path = loader.path(year, day);
+    if path.is_file() {
+        return Ok((path, false));
+    }
+
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+
+    // Written next to the cache then renamed, so an interrupted download is never cached
+    let io_error = |source| ClientError::Io {
+        path: path.clone(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).map_err(io_error)?;
+    fs::rename(&partial, &path).map_err(io_error)?;
+
+    Ok((path, true))
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let path = Path::new("config.toml");
+        let config = ClientConfig::parse(
+            "session = \" abc123\\n\"\nbase_url = \"http://localhost:8080\"",
+            path,
+        )
+        .unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.contact, None);
+
+        assert_eq!(
+            ClientConfig::parse("", path).unwrap(),
+            ClientConfig::default()
+        );
+        assert!(ClientConfig::parse("session = 12", path).is_err());
+    }
+
+    #[test]
+    fn test_user_agent() {
+        assert!(user_agent(None).starts_with("aoc/"));
+        assert!(user_agent(Some("me@example.com")).ends_with("; contact: me@example.com)"));
+    }
+}
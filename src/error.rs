@@ -0,0 +1,277 @@
+//!
+//! # error
+//! The error returned by the solutions when their input doesn't have the expected format.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+
+// Variables  =========================================================================== Variables
+///
+/// # AocError
+///
+/// An input that couldn't be parsed, with the position of the problem when it is known.
+/// Lines and columns start at 1.
+///
+/// A parser of a single line only knows the column of the problem; the line is attached by the
+/// caller iterating over the input, usually through [`crate::parse::lines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    source_line: Option<String>,
+}
+
+impl AocError {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `message` - What is wrong with the input
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            message: message.into(),
+            line: None,
+            column: None,
+            source_line: None,
+        }
+    }
+
+    ///
+    /// # message
+    ///
+    /// ## Returns
+    ///
+    /// * `&str` - What is wrong with the input, without its position
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    ///
+    /// # line
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<usize>` - The line of the problem, if known
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    ///
+    /// # column
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<usize>` - The column of the problem, if known
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    ///
+    /// # at_line
+    ///
+    /// Attach the line of the problem, unless it is already known.
+    ///
+    /// ## Arguments
+    ///
+    /// * `line` - The line number
+    /// * `text` - The content of the line, displayed under the message
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.source_line = Some(text.to_string());
+        }
+        self
+    }
+
+    ///
+    /// # at_column
+    ///
+    /// Attach the column of the problem, unless it is already known.
+    ///
+    /// ## Arguments
+    ///
+    /// * `column` - The column number
+    pub fn at_column(mut self, column: usize) -> Self {
+        if self.column.is_none() {
+            self.column = Some(column);
+        }
+        self
+    }
+
+    ///
+    /// # at_token
+    ///
+    /// Attach the column of a token, which must be a slice of the parsed text.
+    ///
+    /// ## Arguments
+    ///
+    /// * `text` - The parsed text, starting at column 1
+    /// * `token` - The part of `text` that is wrong
+    pub fn at_token(self, text: &str, token: &str) -> Self {
+        match column_of(text, token) {
+            Some(column) => self.at_column(column),
+            None => self,
+        }
+    }
+
+    ///
+    /// # within
+    ///
+    /// Move the column of the problem, found by parsing a part of a text, to its position in
+    /// the whole text. Without a column, the problem is placed at the start of the part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `text` - The whole text, starting at column 1
+    /// * `part` - The parsed part, a slice of `text`
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        if let Some(start) = column_of(text, part) {
+            self.column = Some(self.column.map_or(start, |column| column + start - 1));
+        }
+        self
+    }
+
+    ///
+    /// # offset_lines
+    ///
+    /// Move the position of the problem down, when it was found by parsing a part of the input.
+    ///
+    /// ## Arguments
+    ///
+    /// * `offset` - The number of lines before the parsed part
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+}
+
+///
+/// # column_of
+///
+/// ## Arguments
+///
+/// * `text` - A text, starting at column 1
+/// * `token` - A slice of `text`
+///
+/// ## Returns
+///
+/// * `Option<usize>` - The column of `token` in `text`, `None` if it isn't a slice of it
+fn column_of(text: &str, token: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+
+    if position >= start && position <= start + text.len() {
+        Some(text[..position - start].chars().count() + 1)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)?;
+
+        if let Some(text) = &self.source_line {
+            write!(f, "\n    {}", text)?;
+
+            if let Some(column) = self.column {
+                write!(f, "\n    {:>width$}", "^", width = column)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<ParseIntError> for AocError {
+    fn from(error: ParseIntError) -> Self {
+        AocError::new(format!("invalid integer: {}", error))
+    }
+}
+
+impl From<ParseFloatError> for AocError {
+    fn from(error: ParseFloatError) -> Self {
+        AocError::new(format!("invalid number: {}", error))
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(AocError::new("empty input").to_string(), "empty input");
+        assert_eq!(
+            AocError::new("unknown action `dim`")
+                .at_column(1)
+                .to_string(),
+            "column 1: unknown action `dim`"
+        );
+        assert_eq!(
+            AocError::new("unknown direction `X`")
+                .at_column(1)
+                .at_line(3, "X 4")
+                .to_string(),
+            "line 3, column 1: unknown direction `X`\n    X 4\n    ^"
+        );
+        assert_eq!(
+            AocError::new("missing `->`")
+                .at_line(2, "498,4 498,6")
+                .to_string(),
+            "line 2: missing `->`\n    498,4 498,6"
+        );
+    }
+
+    #[test]
+    fn test_at_token() {
+        let line = "Valve AA has flow rate=1x";
+        let error = AocError::new("invalid flow rate").at_token(line, &line[23..]);
+
+        assert_eq!(error.column(), Some(24));
+        assert_eq!(error.at_token(line, line).column(), Some(24));
+        let other = String::from("AA");
+        assert_eq!(
+            AocError::new("").at_token(line, other.as_str()).column(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let line = "2-4,6-x";
+        let range = &line[4..];
+
+        assert_eq!(
+            AocError::new("invalid number")
+                .at_column(3)
+                .within(line, range)
+                .column(),
+            Some(7)
+        );
+        assert_eq!(AocError::new("").within(line, range).column(), Some(5));
+    }
+
+    #[test]
+    fn test_positions_are_kept() {
+        let error = AocError::new("invalid item")
+            .at_line(2, "  Starting items: 79, x")
+            .offset_lines(5)
+            .at_line(1, "Monkey 0:");
+
+        assert_eq!(error.line(), Some(7));
+    }
+}
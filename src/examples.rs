@@ -0,0 +1,234 @@
+//!
+//! # examples
+//! The examples given in the puzzle descriptions, with their expected answers.
+//!
+//! The examples of a year are stored next to its inputs, in
+//! `<data_dir>/year_XXXX/examples/day_XX_N.txt`, where `N` numbers the examples of a day.
+//! Their answers are in `<data_dir>/year_XXXX/examples/answers.toml`, keyed by file name:
+//!
+//! ```toml
+//! [day_05_1]
+//! part_1 = "CMZ"
+//! part_2 = "MCD"
+//! ```
+//!
+//! An example only checks the parts it has an answer for, since some examples are only
+//! given for one part.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, AnswersError};
+use crate::error::AocError;
+use crate::registry::Puzzle;
+use crate::solution::{Answer, Part};
+
+// Variables  =========================================================================== Variables
+/// Name of the examples folder, inside the folder of a year.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Name of the file holding the answers of the examples, inside the examples folder.
+pub const EXAMPLE_ANSWERS_FILE: &str = "answers.toml";
+
+///
+/// # Example
+///
+/// An example input of a puzzle, with the answers it should give.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub index: u8,
+    pub input: String,
+    /// The expected answer of each part, `None` when the example doesn't give one.
+    pub expected: [Option<String>; 2],
+}
+
+///
+/// # Mismatch
+///
+/// A part of an example giving another answer than the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub part: Part,
+    pub expected: String,
+    pub answer: Answer,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "part {}: expected {:?}, got {:?}",
+            self.part,
+            self.expected,
+            self.answer.to_string()
+        )
+    }
+}
+
+impl Example {
+    ///
+    /// # path
+    ///
+    /// ## Arguments
+    ///
+    /// * `data_dir` - The data directory
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `index` - The number of the example, starting at 1
+    ///
+    /// ## Returns
+    ///
+    /// * `PathBuf` - Where the example input is expected
+    pub fn path(data_dir: &Path, year: u16, day: u8, index: u8) -> PathBuf {
+        examples_dir(data_dir, year).join(format!("day_{:02}_{}.txt", day, index))
+    }
+
+    ///
+    /// # load
+    ///
+    /// ## Arguments
+    ///
+    /// * `data_dir` - The data directory
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `index` - The number of the example, starting at 1
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Example, AnswersError>` - The example and its answers
+    pub fn load(data_dir: &Path, year: u16, day: u8, index: u8) -> Result<Self, AnswersError> {
+        let path = Example::path(data_dir, year, day, index);
+        let input = fs::read_to_string(&path).map_err(|source| AnswersError::Io {
+            path: path.clone(),
+            source,
+        })?;
+
+        let answers_path = examples_dir(data_dir, year).join(EXAMPLE_ANSWERS_FILE);
+        let content = match fs::read_to_string(&answers_path) {
+            Ok(content) => content,
+            Err(source) if source.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(AnswersError::Io {
+                    path: answers_path,
+                    source,
+                })
+            }
+        };
+        let table: toml::Table = content.parse().map_err(|source| AnswersError::Toml {
+            path: answers_path.clone(),
+            source,
+        })?;
+
+        let key = format!("day_{:02}_{}", day, index);
+        let mut expected = [None, None];
+        if let Some(parts) = table.get(&key) {
+            let parts = answers::parse_parts(parts, &key).map_err(|key| AnswersError::Invalid {
+                path: answers_path.clone(),
+                key,
+            })?;
+
+            for (part, answer) in parts {
+                expected[part as usize] = Some(answer);
+            }
+        }
+
+        Ok(Example {
+            year,
+            day,
+            index,
+            input,
+            expected,
+        })
+    }
+
+    ///
+    /// # check
+    ///
+    /// Solve the example and compare it with the expected answers.
+    ///
+    /// ## Arguments
+    ///
+    /// * `puzzle` - The solution of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Vec<Mismatch>, AocError>` - The parts giving a wrong answer, empty if the
+    ///   example passes, or why the example can't be parsed
+    pub fn check(&self, puzzle: &Puzzle) -> Result<Vec<Mismatch>, AocError> {
+        let parsed = puzzle.parse(&self.input)?;
+
+        Ok(Part::ALL
+            .iter()
+            .filter_map(|&part| {
+                let expected = self.expected[part as usize].as_ref()?;
+                let answer = puzzle.solve_parsed(parsed.as_ref(), part);
+
+                (!answers::matches(&answer, expected)).then(|| Mismatch {
+                    part,
+                    expected: expected.clone(),
+                    answer,
+                })
+            })
+            .collect())
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # examples_dir
+///
+/// ## Arguments
+///
+/// * `data_dir` - The data directory
+/// * `year` - The year of the examples
+///
+/// ## Returns
+///
+/// * `PathBuf` - The folder holding the examples of the year
+fn examples_dir(data_dir: &Path, year: u16) -> PathBuf {
+    data_dir.join(format!("year_{}", year)).join(EXAMPLES_DIR)
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            Example::path(Path::new("data"), 2022, 9, 2),
+            PathBuf::from("data/year_2022/examples/day_09_2.txt")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let puzzle = crate::registry::find(2015, 1).unwrap();
+        let mut example = Example {
+            year: 2015,
+            day: 1,
+            index: 1,
+            input: "()())".to_string(),
+            expected: [Some("-1".to_string()), None],
+        };
+
+        assert_eq!(example.check(puzzle), Ok(vec![]));
+
+        example.expected[1] = Some("4".to_string());
+        assert_eq!(
+            example.check(puzzle),
+            Ok(vec![Mismatch {
+                part: Part::Two,
+                expected: "4".to_string(),
+                answer: Answer::Number(5),
+            }])
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//!
+//! # grid
+//! A rectangular 2D map, stored in a single vector, for the puzzles drawn as text.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::parse;
+use crate::point::Point;
+
+// Variables  =========================================================================== Variables
+/// The offsets to the four neighbours of a cell: up, right, down and left.
+pub const DIRECTIONS_4: [Point<isize>; 4] = [
+    Point { x: 0, y: -1 },
+    Point { x: 1, y: 0 },
+    Point { x: 0, y: 1 },
+    Point { x: -1, y: 0 },
+];
+
+///
+/// # Grid
+///
+/// A rectangle of `width` × `height` cells, stored row by row.
+/// The cells are indexed by a `Point<usize>`, `x` being the column and `y` the row,
+/// with `(0, 0)` at the top-left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of columns
+    /// * `height` - The number of rows
+    /// * `value` - The value of every cell
+    ///
+    /// ## Returns
+    ///
+    /// * `Grid<T>` - The grid filled with `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    ///
+    /// # from_fn
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of columns
+    /// * `height` - The number of rows
+    /// * `cell` - The value of the cell at a position
+    ///
+    /// ## Returns
+    ///
+    /// * `Grid<T>` - The grid of the computed cells
+    pub fn from_fn(width: usize, height: usize, cell: impl FnMut(Point<usize>) -> T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+                .map(cell)
+                .collect(),
+        }
+    }
+
+    ///
+    /// # parse
+    ///
+    /// Parse a grid drawn as text, one character per cell.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The lines of the grid
+    /// * `cell` - The parser of a character, given its position
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Grid<T>, AocError>` - The grid, or the position of the first invalid cell or
+    ///   of the first row of another length
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char, Point<usize>) -> Result<T, AocError>,
+    ) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut y = 0;
+        let mut cells = Vec::new();
+
+        parse::lines_with(input, |line| {
+            let length = line.chars().count();
+            if *width.get_or_insert(length) != length {
+                return Err(AocError::new(format!(
+                    "expected a row of {} cells, found {}",
+                    width.unwrap_or_default(),
+                    length
+                )));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c, Point::new(x, y)).map_err(|error| error.at_column(x + 1))?);
+            }
+            y += 1;
+
+            Ok(())
+        })?;
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height: y,
+            cells,
+        })
+    }
+
+    ///
+    /// # width
+    ///
+    /// ## Returns
+    ///
+    /// * `usize` - The number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    ///
+    /// # height
+    ///
+    /// ## Returns
+    ///
+    /// * `usize` - The number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    ///
+    /// # contains
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Whether the position is inside the grid
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    ///
+    /// # get
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&T>` - The cell at this position, `None` outside of the grid
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    ///
+    /// # get_mut
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&mut T>` - The cell at this position, `None` outside of the grid
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// # step
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    /// * `direction` - The offset to apply, like one of [`DIRECTIONS_4`]
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Point<usize>>` - The moved position, `None` if it leaves the grid
+    pub fn step(&self, point: Point<usize>, direction: Point<isize>) -> Option<Point<usize>> {
+        point
+            .checked_offset(direction)
+            .filter(|&next| self.contains(next))
+    }
+
+    ///
+    /// # neighbors4
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<usize>>` - The horizontal and vertical neighbours of the
+    ///   position that are inside the grid
+    pub fn neighbors4(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.neighbors4().filter(|&next| self.contains(next))
+    }
+
+    ///
+    /// # neighbors8
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - A position
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<usize>>` - The neighbours of the position, diagonals
+    ///   included, that are inside the grid
+    pub fn neighbors8(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point.neighbors8().filter(|&next| self.contains(next))
+    }
+
+    ///
+    /// # ray
+    ///
+    /// ## Arguments
+    ///
+    /// * `start` - A position, not included in the ray
+    /// * `direction` - The offset between two positions of the ray
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<usize>>` - The positions from `start` in `direction`,
+    ///   until the edge of the grid
+    pub fn ray(
+        &self,
+        start: Point<usize>,
+        direction: Point<isize>,
+    ) -> impl Iterator<Item = Point<usize>> + '_ {
+        std::iter::successors(self.step(start, direction), move |&point| {
+            self.step(point, direction)
+        })
+    }
+
+    ///
+    /// # row
+    ///
+    /// ## Arguments
+    ///
+    /// * `y` - The index of the row
+    ///
+    /// ## Returns
+    ///
+    /// * `&[T]` - The cells of the row, from left to right
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    ///
+    /// # row_mut
+    ///
+    /// ## Arguments
+    ///
+    /// * `y` - The index of the row
+    ///
+    /// ## Returns
+    ///
+    /// * `&mut [T]` - The cells of the row, from left to right
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    ///
+    /// # rows
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = &[T]>` - The rows, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    ///
+    /// # column
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The index of the column
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = &T>` - The cells of the column, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} out of a grid of width {}",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    ///
+    /// # points
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<usize>>` - Every position of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    ///
+    /// # iter
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = (Point<usize>, &T)>` - Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    ///
+    /// # values
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = &T>` - Every cell, row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    ///
+    /// # position
+    ///
+    /// ## Arguments
+    ///
+    /// * `predicate` - The condition on a cell
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Point<usize>>` - The position of the first matching cell, row by row
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    ///
+    /// # find
+    ///
+    /// ## Arguments
+    ///
+    /// * `value` - The value to look for, like the `S` marker of a map
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Point<usize>>` - The position of its first occurrence, row by row
+    pub fn find(&self, value: &T) -> Option<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    ///
+    /// # map
+    ///
+    /// ## Arguments
+    ///
+    /// * `f` - The conversion of a cell
+    ///
+    /// ## Returns
+    ///
+    /// * `Grid<U>` - A grid of the same size holding the converted cells
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c, _| Ok(c))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "Sab\ncdE";
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'E'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'a');
+        assert_eq!(grid.to_string(), "Sab\ncdE\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = "ab\nabc".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error.message(), "expected a row of 2 cells, found 3");
+        assert_eq!(error.line(), Some(2));
+
+        let error = Grid::parse("12\n3x", |c, _| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::new("expected a digit"))
+        })
+        .unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_find_and_get_mut() {
+        let mut grid: Grid<char> = MAP.parse().unwrap();
+
+        assert_eq!(grid.find(&'E'), Some(Point::new(2, 1)));
+        assert_eq!(grid.find(&'z'), None);
+
+        *grid.get_mut(Point::new(0, 0)).unwrap() = 'a';
+        grid[Point::new(2, 1)] = 'z';
+        assert_eq!(grid.to_string(), "aab\ncdz\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Point::new(1, 1))
+                .map(|point| grid[point])
+                .collect::<String>(),
+            "ei"
+        );
+        assert_eq!(grid.ray(Point::new(1, 1), Point::new(0, -1)).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of a grid of width 3")]
+    fn test_column_out_of_the_grid() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let _ = grid.column(3);
+    }
+}
@@ -0,0 +1,659 @@
+//!
+//! # image
+//! Export of grids and simulation frames to image files.
+//!
+//! * [`Image`] - An RGB image, written as PPM or PNG
+//! * [`Palette`] and [`ramp`] - Colors for the cells of a grid: one per symbol, or a heightmap
+//!   ramp for numbers
+//! * [`export_frames`] and [`export_gif`] - Every frame of a [`Visualize`] simulation, as
+//!   numbered images or an animated GIF
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::visualize::Visualize;
+
+// Variables  =========================================================================== Variables
+/// A red, green and blue color.
+pub type Color = [u8; 3];
+
+/// The colors of the heightmap ramp, from the lowest to the highest value.
+const RAMP: [Color; 5] = [
+    [20, 30, 90],
+    [30, 110, 170],
+    [50, 160, 80],
+    [230, 210, 90],
+    [250, 250, 250],
+];
+
+///
+/// # ImageFormat
+///
+/// The formats a still image can be written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    ///
+    /// # extension
+    ///
+    /// ## Returns
+    ///
+    /// * `&str` - The file extension of the format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("unknown image format `{}`, expected ppm or png", s)),
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+///
+/// # Palette
+///
+/// The color of each symbol of a rendered frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<(char, Color)>,
+    fallback: Color,
+}
+
+impl Default for Palette {
+    /// The symbols used by the simulations of the repository.
+    fn default() -> Self {
+        Palette::new(&[
+            ('.', [15, 15, 35]),
+            (' ', [0, 0, 0]),
+            ('#', [120, 120, 130]),
+            ('o', [225, 185, 90]),
+            ('~', [255, 235, 130]),
+            ('+', [220, 50, 50]),
+            ('*', [220, 50, 50]),
+            ('@', [255, 255, 255]),
+        ])
+    }
+}
+
+impl Palette {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `colors` - The color of each symbol; the other symbols are light grey
+    pub fn new(colors: &[(char, Color)]) -> Self {
+        Palette {
+            colors: colors.to_vec(),
+            fallback: [200, 200, 200],
+        }
+    }
+
+    ///
+    /// # with_fallback
+    ///
+    /// ## Arguments
+    ///
+    /// * `fallback` - The color of the symbols missing from the palette
+    pub fn with_fallback(mut self, fallback: Color) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    ///
+    /// # color
+    ///
+    /// ## Arguments
+    ///
+    /// * `symbol` - A symbol of a frame
+    ///
+    /// ## Returns
+    ///
+    /// * `Color` - Its color
+    pub fn color(&self, symbol: char) -> Color {
+        self.colors
+            .iter()
+            .find(|(c, _)| *c == symbol)
+            .map_or(self.fallback, |&(_, color)| color)
+    }
+}
+
+///
+/// # Image
+///
+/// An RGB image, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    ///
+    /// # from_grid
+    ///
+    /// ## Arguments
+    ///
+    /// * `grid` - The grid to draw, one pixel per cell
+    /// * `color` - The color of a cell
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Color) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.values().map(color).collect(),
+        }
+    }
+
+    ///
+    /// # from_chars
+    ///
+    /// ## Arguments
+    ///
+    /// * `grid` - A rendered frame
+    /// * `palette` - The color of each symbol
+    pub fn from_chars(grid: &Grid<char>, palette: &Palette) -> Self {
+        Image::from_grid(grid, |&symbol| palette.color(symbol))
+    }
+
+    ///
+    /// # heightmap
+    ///
+    /// Color a grid of numbers along the heightmap ramp, from its lowest to its highest value.
+    ///
+    /// ## Arguments
+    ///
+    /// * `grid` - The grid to draw
+    /// * `value` - The number of a cell
+    pub fn heightmap<T>(grid: &Grid<T>, value: impl Fn(&T) -> f64) -> Self {
+        let (min, max) = grid
+            .values()
+            .map(&value)
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+        let range = if max > min { max - min } else { 1.0 };
+
+        Image::from_grid(grid, |cell| ramp((value(cell) - min) / range))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    ///
+    /// # pixel
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The column of the pixel
+    /// * `y` - The row of the pixel
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Color>` - Its color, `None` outside of the image
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Color> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    ///
+    /// # set_pixel
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The column of the pixel
+    /// * `y` - The row of the pixel
+    /// * `color` - Its new color, ignored outside of the image
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    ///
+    /// # scaled
+    ///
+    /// ## Arguments
+    ///
+    /// * `factor` - The size of a pixel in the new image, `0` being treated as `1`
+    ///
+    /// ## Returns
+    ///
+    /// * `Image` - The image enlarged, each pixel becoming a `factor`×`factor` square
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        let width = self.width * factor;
+        let height = self.height * factor;
+
+        Image {
+            width,
+            height,
+            pixels: (0..width * height)
+                .map(|i| self.pixels[(i / width / factor) * self.width + (i % width) / factor])
+                .collect(),
+        }
+    }
+
+    ///
+    /// # rgb
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<u8>` - The red, green and blue bytes of every pixel, row by row
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    ///
+    /// # write_ppm
+    ///
+    /// Write the image as a binary PPM (`P6`).
+    ///
+    /// ## Arguments
+    ///
+    /// * `out` - Where the image is written
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.rgb())?;
+        out.flush()
+    }
+
+    ///
+    /// # write_png
+    ///
+    /// ## Arguments
+    ///
+    /// * `out` - Where the image is written
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let (width, height) = (dimension(self.width)?, dimension(self.height)?);
+        let mut encoder = png::Encoder::new(out, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.rgb())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    ///
+    /// # write
+    ///
+    /// ## Arguments
+    ///
+    /// * `out` - Where the image is written
+    /// * `format` - The format of the image
+    pub fn write(&self, out: impl Write, format: ImageFormat) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+        }
+    }
+
+    ///
+    /// # save
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The file to create, its extension giving the format
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .ok_or_else(|| io::Error::other(format!("{} has no extension", path.display())))?
+            .parse::<ImageFormat>()
+            .map_err(io::Error::other)?;
+
+        self.write(BufWriter::new(File::create(path)?), format)
+    }
+}
+
+///
+/// # ExportOptions
+///
+/// Which frames of a simulation are exported, and how.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// The size of a cell in pixels.
+    pub scale: usize,
+    /// Keep one frame out of `every`; the last frame is always kept.
+    pub every: usize,
+    /// Frames per second of the GIF animations.
+    pub frame_rate: u32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            scale: 4,
+            every: 1,
+            frame_rate: 30,
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # ramp
+///
+/// ## Arguments
+///
+/// * `value` - A position along the ramp, from `0.0` to `1.0`
+///
+/// ## Returns
+///
+/// * `Color` - The color of the heightmap ramp at that position, clamped to its ends
+pub fn ramp(value: f64) -> Color {
+    let position = value.clamp(0.0, 1.0) * (RAMP.len() - 1) as f64;
+    let index = (position as usize).min(RAMP.len() - 2);
+    let t = position - index as f64;
+    let (from, to) = (RAMP[index], RAMP[index + 1]);
+
+    [0, 1, 2].map(|c| (from[c] as f64 + (to[c] as f64 - from[c] as f64) * t).round() as u8)
+}
+
+///
+/// # dimension
+///
+/// ## Arguments
+///
+/// * `size` - A width or a height
+///
+/// ## Returns
+///
+/// * `io::Result<T>` - The size in the integer type of an encoder, or an error if it is too large
+fn dimension<T: TryFrom<usize>>(size: usize) -> io::Result<T> {
+    T::try_from(size).map_err(|_| io::Error::other(format!("{} pixels is too large", size)))
+}
+
+///
+/// # for_each_frame
+///
+/// Run a simulation until it is over, handing over the selected frames.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to run
+/// * `options` - Which frames are kept, and their scale
+/// * `f` - What to do with a kept frame and its index among the kept ones
+///
+/// ## Returns
+///
+/// * `io::Result<usize>` - The number of frames kept
+fn for_each_frame(
+    simulation: &mut dyn Visualize,
+    options: &ExportOptions,
+    mut f: impl FnMut(usize, Image) -> io::Result<()>,
+) -> io::Result<usize> {
+    let every = options.every.max(1);
+    let mut kept = 0;
+
+    for step in 0.. {
+        let over = step > 0 && !simulation.step();
+        if over && (step - 1) % every == 0 {
+            // The last frame was kept already
+            break;
+        }
+        if over || step % every == 0 {
+            f(kept, simulation.image().scaled(options.scale))?;
+            kept += 1;
+        }
+        if over {
+            break;
+        }
+    }
+
+    Ok(kept)
+}
+
+///
+/// # export_frames
+///
+/// Write every selected frame of a simulation to a numbered file: `frame_00000.png`, ...
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to export
+/// * `directory` - Where the frames are written, created if needed
+/// * `format` - The format of the frames
+/// * `options` - Which frames are kept, and their scale
+///
+/// ## Returns
+///
+/// * `io::Result<Vec<PathBuf>>` - The files written, in order
+pub fn export_frames(
+    simulation: &mut dyn Visualize,
+    directory: &Path,
+    format: ImageFormat,
+    options: &ExportOptions,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory)?;
+    let mut paths = Vec::new();
+
+    for_each_frame(simulation, options, |index, image| {
+        let path = directory.join(format!("frame_{:05}.{}", index, format.extension()));
+        image.save(&path)?;
+        paths.push(path);
+        Ok(())
+    })?;
+
+    Ok(paths)
+}
+
+///
+/// # export_gif
+///
+/// Write the selected frames of a simulation as an animated GIF, looping forever.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to export
+/// * `out` - Where the animation is written
+/// * `options` - Which frames are kept, their scale and the frame rate
+///
+/// ## Returns
+///
+/// * `io::Result<usize>` - The number of frames written
+pub fn export_gif(
+    simulation: &mut dyn Visualize,
+    out: impl Write,
+    options: &ExportOptions,
+) -> io::Result<usize> {
+    // The delays of a GIF are in hundredths of a second, and most viewers slow down below 2
+    let delay = (100 / options.frame_rate.max(1)).max(2) as u16;
+    let mut out = Some(out);
+    let mut encoder: Option<(gif::Encoder<_>, usize, usize)> = None;
+
+    for_each_frame(simulation, options, |_, image| {
+        if encoder.is_none() {
+            let out = out.take().expect("the encoder is only created once");
+            let mut gif =
+                gif::Encoder::new(out, dimension(image.width)?, dimension(image.height)?, &[])
+                    .map_err(io::Error::other)?;
+            gif.set_repeat(gif::Repeat::Infinite)
+                .map_err(io::Error::other)?;
+            encoder = Some((gif, image.width, image.height));
+        }
+        let Some((gif, width, height)) = encoder.as_mut() else {
+            unreachable!("the encoder was just created");
+        };
+
+        if (image.width, image.height) != (*width, *height) {
+            return Err(io::Error::other(
+                "the frames of a GIF must have the same size",
+            ));
+        }
+
+        let mut frame = gif::Frame::from_rgb_speed(
+            dimension(image.width)?,
+            dimension(image.height)?,
+            &image.rgb(),
+            10,
+        );
+        frame.delay = delay;
+        gif.write_frame(&frame).map_err(io::Error::other)
+    })
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    /// A cell filling a 3×2 frame, one more per step.
+    struct Fill {
+        filled: usize,
+    }
+
+    impl Visualize for Fill {
+        fn step(&mut self) -> bool {
+            if self.filled == 6 {
+                return false;
+            }
+            self.filled += 1;
+            true
+        }
+
+        fn render(&self) -> Grid<char> {
+            Grid::from_fn(3, 2, |point: Point<usize>| {
+                if point.y * 3 + point.x < self.filled {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+        }
+    }
+
+    #[test]
+    fn test_from_chars_and_ppm() {
+        let grid: Grid<char> = "#.\n.x".parse().unwrap();
+        let palette =
+            Palette::new(&[('#', [255, 0, 0]), ('.', [0, 0, 0])]).with_fallback([1, 2, 3]);
+        let image = Image::from_chars(&grid, &palette);
+
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+
+        assert_eq!(image.pixel(1, 1), Some([1, 2, 3]));
+        assert_eq!(image.pixel(2, 0), None);
+        assert_eq!(&out[..11], b"P6\n2 2\n255\n");
+        assert_eq!(&out[11..], &[255, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_heightmap_and_ramp() {
+        let grid = Grid::from_fn(3, 1, |point: Point<usize>| point.x as u64 * 10);
+        let image = Image::heightmap(&grid, |&value| value as f64);
+
+        assert_eq!(image.pixel(0, 0), Some(RAMP[0]));
+        assert_eq!(image.pixel(1, 0), Some(RAMP[2]));
+        assert_eq!(image.pixel(2, 0), Some(RAMP[4]));
+        assert_eq!(ramp(-1.0), RAMP[0]);
+        assert_eq!(ramp(0.125), [25, 70, 130]);
+    }
+
+    #[test]
+    fn test_scaled() {
+        let grid: Grid<char> = "#.".parse().unwrap();
+        let image = Image::from_chars(&grid, &Palette::default()).scaled(2);
+
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.pixel(1, 1), image.pixel(0, 0));
+        assert_eq!(image.pixel(2, 1), Some(Palette::default().color('.')));
+    }
+
+    #[test]
+    fn test_write_png() {
+        let grid: Grid<char> = "#.o\n~+.".parse().unwrap();
+        let image = Image::from_chars(&grid, &Palette::default());
+        let mut out = Vec::new();
+        image.write_png(&mut out).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut bytes = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut bytes).unwrap();
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&bytes[..info.buffer_size()], image.rgb().as_slice());
+    }
+
+    #[test]
+    fn test_export_frames() {
+        let directory = std::env::temp_dir().join(format!("aoc_frames_{}", std::process::id()));
+        let options = ExportOptions {
+            scale: 1,
+            every: 4,
+            frame_rate: 30,
+        };
+
+        let paths = export_frames(
+            &mut Fill { filled: 0 },
+            &directory,
+            ImageFormat::Ppm,
+            &options,
+        )
+        .unwrap();
+        let last = fs::read(&paths[2]).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        // The frames 0 and 4, then the last one
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("frame_00000.ppm"));
+        assert!(last[11..].chunks(3).all(|pixel| pixel == [120, 120, 130]));
+    }
+
+    #[test]
+    fn test_export_gif() {
+        let mut out = Vec::new();
+        let options = ExportOptions {
+            scale: 2,
+            every: 1,
+            frame_rate: 10,
+        };
+
+        let frames = export_gif(&mut Fill { filled: 0 }, &mut out, &options).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new().read_info(out.as_slice()).unwrap();
+        let mut decoded = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height, frame.delay), (6, 4, 10));
+            decoded += 1;
+        }
+
+        assert_eq!(frames, 7);
+        assert_eq!(decoded, 7);
+    }
+}
@@ -0,0 +1,234 @@
+//!
+//! # input
+//! Loading of the puzzle inputs, which are not committed with the solutions.
+//!
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the data directory.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Data directory used when nothing else is configured, relative to the working directory.
+pub const DEFAULT_DATA_DIR: &str = "data";
+
+// Inputs compiled into the binary with the `embed-inputs` feature, generated by `build.rs`.
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+}
+
+///
+/// # InputError
+///
+/// Error returned when the input of a puzzle can't be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input file for the puzzle.
+    Missing { year: u16, day: u8, path: PathBuf },
+    /// The input file exists but couldn't be read.
+    Io {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => write!(
+                f,
+                "input missing for {} day {} (expected at {})",
+                year,
+                day,
+                path.display()
+            ),
+            InputError::Io {
+                year,
+                day,
+                path,
+                source,
+            } => write!(
+                f,
+                "can't read input for {} day {} at {}: {}",
+                year,
+                day,
+                path.display(),
+                source
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+///
+/// # InputLoader
+///
+/// Resolves puzzle inputs at runtime, from `<data_dir>/year_XXXX/inputs/day_XX.txt`.
+/// When built with the `embed-inputs` feature, the inputs found at compile time are
+/// served from the binary first.
+#[derive(Debug, Clone)]
+pub struct InputLoader {
+    data_dir: PathBuf,
+}
+
+impl InputLoader {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `data_dir` - The directory containing the `year_XXXX` folders
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        InputLoader {
+            data_dir: data_dir.into(),
+        }
+    }
+
+    ///
+    /// # from_env
+    ///
+    /// Create a loader using the `AOC_DATA_DIR` environment variable,
+    /// or the `data` directory if it isn't set.
+    pub fn from_env() -> Self {
+        match env::var_os(DATA_DIR_ENV) {
+            Some(data_dir) if !data_dir.is_empty() => InputLoader::new(data_dir),
+            _ => InputLoader::new(DEFAULT_DATA_DIR),
+        }
+    }
+
+    ///
+    /// # data_dir
+    ///
+    /// ## Returns
+    ///
+    /// * `&Path` - The directory containing the `year_XXXX` folders
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+    ///
+    /// # path
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `PathBuf` - Where the input of the puzzle is expected on disk
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.data_dir
+            .join(format!("year_{}", year))
+            .join("inputs")
+            .join(format!("day_{:02}.txt", day))
+    }
+
+    ///
+    /// # load
+    ///
+    /// Load the input of a puzzle.
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<String, InputError>` - The raw input, or why it couldn't be loaded
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        if let Some(input) = embedded_input(year, day) {
+            return Ok(input.to_string());
+        }
+
+        let path = self.path(year, day);
+        fs::read_to_string(&path).map_err(|source| {
+            if source.kind() == io::ErrorKind::NotFound {
+                InputError::Missing { year, day, path }
+            } else {
+                InputError::Io {
+                    year,
+                    day,
+                    path,
+                    source,
+                }
+            }
+        })
+    }
+}
+
+impl Default for InputLoader {
+    fn default() -> Self {
+        InputLoader::from_env()
+    }
+}
+
+#[cfg(feature = "embed-inputs")]
+fn embedded_input(year: u16, day: u8) -> Option<&'static str> {
+    embedded::EMBEDDED_INPUTS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|(_, _, input)| *input)
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn embedded_input(_year: u16, _day: u8) -> Option<&'static str> {
+    None
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let loader = InputLoader::new("somewhere");
+
+        assert_eq!(
+            loader.path(2022, 5),
+            PathBuf::from("somewhere/year_2022/inputs/day_05.txt")
+        );
+    }
+
+    #[cfg(not(feature = "embed-inputs"))]
+    #[test]
+    fn test_load_missing() {
+        let loader = InputLoader::new("this/directory/does/not/exist");
+
+        match loader.load(2022, 16) {
+            Err(error @ InputError::Missing { .. }) => assert_eq!(
+                error.to_string(),
+                "input missing for 2022 day 16 (expected at this/directory/does/not/exist/year_2022/inputs/day_16.txt)"
+            ),
+            other => panic!("expected a missing input, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        let data_dir = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        let loader = InputLoader::new(&data_dir);
+        fs::create_dir_all(loader.path(1999, 1).parent().unwrap()).unwrap();
+        fs::write(loader.path(1999, 1), "(()))").unwrap();
+
+        let input = loader.load(1999, 1);
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(input.unwrap(), "(()))");
+    }
+}
@@ -0,0 +1,55 @@
+//!
+//! # aoc
+//! Advent of Code solutions, and the utilities shared between them.
+//!
+//! * [`solution`] - The `Solution` trait implemented by every day, and its `Answer`
+//! * [`input`] - Loading of the puzzle inputs from the data directory
+//! * [`client`] - Download of the inputs from the website, used by `aoc fetch`
+//! * [`submit`] - Submission of the answers with a local guess history, used by `aoc submit`
+//! * [`registry`] - The table of every implemented day, used by the `aoc` runner
+//! * [`answers`] - The known correct answers, checked by `aoc verify`
+//! * [`runner`] - Parallel run of many puzzles with a summary, used by `aoc run --all`
+//! * [`bench`] - Timing of the solutions, used by `aoc bench`
+//! * [`examples`] - The examples of the puzzle descriptions, run by the test suite
+//! * [`error`] - The error returned by the solutions on a malformed input
+//! * [`parse`] - Helpers to parse the inputs, reporting the position of a problem
+//! * [`point`] - A generic 2D point
+//! * [`grid`] - A rectangular 2D map indexed by points
+//! * [`search`] - Shortest path searches: BFS, Dijkstra and A*
+//! * [`simulation`] - Step-wise simulations, run for a while, until stable or through a cycle
+//! * [`visualize`] - Terminal animation of the simulations, shown by `aoc run --visualize`
+//! * [`scaffold`] - Creation of the files of a new day, used by `aoc new`
+//! * [`ocr`] - Recognition of the block letters drawn by some puzzles
+//! * [`image`] - PPM, PNG and GIF export of the grids and simulations, used by `aoc export`
+//!
+//! The solutions themselves live in one module per year, with one module per day.
+//!
+
+// The `FromInput` derive refers to the crate as `aoc`, also from the inside
+extern crate self as aoc;
+
+// Modules  =============================================================================== Modules
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod submit;
+pub mod visualize;
+
+pub mod year_2015;
+pub mod year_2022;
+pub mod year_2023;
+pub mod year_2024;
@@ -0,0 +1,795 @@
+//!
+//! # main.rs
+//! Runner of the Advent of Code solutions
+//!
+
+// Imports  ==============================================================================  Imports
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use aoc::answers::{AnswerStore, Verdict};
+use aoc::bench::{self, BenchOptions};
+use aoc::client::{self, Client, ClientConfig};
+use aoc::image::{self, ExportOptions, ImageFormat};
+use aoc::input::InputLoader;
+use aoc::registry::{self, Puzzle};
+use aoc::runner;
+use aoc::scaffold;
+use aoc::solution::{Answer, Part};
+use aoc::submit::{self, Feedback, GuessHistory};
+use aoc::visualize::{self, VisualizeOptions};
+
+// Variables  =========================================================================== Variables
+const USAGE: &str = "\
+Usage: aoc [--data-dir <dir>] <command>
+
+Commands:
+  run <year> <day> [--part <1|2>]  Solve a puzzle
+      [--visualize [--fps <n>]]    Animate it in the terminal first, for the days that can
+  run --all [--year <year>]        Solve every puzzle in parallel and print a summary
+      [--threads <n>]
+  fetch <year> <day>               Download the input of a puzzle, unless it is already cached
+  submit <year> <day> <part>       Send the answer of a part, unless data/guesses.toml knows it
+                                   is wrong
+  new <year> <day>                 Create a new day from the template, with an empty example
+  verify [<year> [<day>]]          Check the answers against data/answers.toml
+  bench [<year> [<day>]]           Time the parsing and both parts of the puzzles
+        [--iterations <n>] [--warmup <n>] [--markdown]
+  export <year> <day>              Save the frames of a visualization as images, in `output`
+         [--format <png|ppm|gif>] [--output <dir>] [--scale <n>] [--every <n>] [--fps <n>]
+  list [<year>]                    List the implemented puzzles
+  help                             Print this message
+
+Options:
+  --data-dir <dir>  Directory containing the inputs (defaults to $AOC_DATA_DIR, then `data`)
+
+Environment:
+  AOC_SESSION   Session cookie of the website, for `fetch` and `submit`
+  AOC_BASE_URL  Address of the website (defaults to https://adventofcode.com)
+  AOC_CONFIG    Configuration file (defaults to ~/.config/aoc/config.toml)";
+
+// Options followed by a value, without the leading dashes.
+const VALUE_OPTIONS: [&str; 11] = [
+    "data-dir",
+    "part",
+    "iterations",
+    "warmup",
+    "fps",
+    "format",
+    "output",
+    "scale",
+    "every",
+    "year",
+    "threads",
+];
+
+// Options without a value, without the leading dashes.
+const FLAGS: [&str; 3] = ["markdown", "visualize", "all"];
+
+///
+/// # Args
+///
+/// The command line arguments, split into positionals, `--name value` options and `--flag`s.
+struct Args {
+    positionals: Vec<String>,
+    options: Vec<(String, String)>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    ///
+    /// # parse
+    ///
+    /// ## Arguments
+    ///
+    /// * `raw` - The arguments, without the program name
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Args, String>` - The parsed arguments, or the reason they are invalid
+    fn parse(raw: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = Args {
+            positionals: Vec::new(),
+            options: Vec::new(),
+            flags: Vec::new(),
+        };
+        let mut raw = raw.peekable();
+
+        while let Some(arg) = raw.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                args.positionals.push(arg);
+                continue;
+            };
+
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (option.to_string(), None),
+            };
+
+            if FLAGS.contains(&name.as_str()) && value.is_none() {
+                args.flags.push(name);
+                continue;
+            }
+
+            if !VALUE_OPTIONS.contains(&name.as_str()) {
+                return Err(format!("unknown option `--{}`", name));
+            }
+
+            let value = match value {
+                Some(value) => value,
+                None => raw
+                    .next()
+                    .ok_or_else(|| format!("missing value for `--{}`", name))?,
+            };
+            args.options.push((name, value));
+        }
+
+        Ok(args)
+    }
+
+    ///
+    /// # command
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&str>` - The subcommand, which is the first positional argument
+    fn command(&self) -> Option<&str> {
+        self.positionals.first().map(String::as_str)
+    }
+
+    ///
+    /// # option
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - The name of the option, without the leading dashes
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&str>` - The last value given to the option
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    ///
+    /// # flag
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` - The name of the flag, without the leading dashes
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Whether the flag is given
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    ///
+    /// # positional
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the argument, after the subcommand
+    /// * `name` - The name of the argument, for error messages
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<T, String>` - The parsed argument
+    fn positional<T: std::str::FromStr>(&self, index: usize, name: &str) -> Result<T, String> {
+        let value = self
+            .positionals
+            .get(index + 1)
+            .ok_or_else(|| format!("missing <{}>", name))?;
+
+        value
+            .parse()
+            .map_err(|_| format!("invalid <{}> `{}`", name, value))
+    }
+
+    ///
+    /// # puzzles
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Vec<&Puzzle>, String>` - The implemented puzzles matching the optional
+    ///   `[<year> [<day>]]` positionals
+    fn puzzles(&self) -> Result<Vec<&'static Puzzle>, String> {
+        let year: Option<u16> = match self.positionals.get(1) {
+            Some(_) => Some(self.positional(0, "year")?),
+            None => None,
+        };
+        let day: Option<u8> = match self.positionals.get(2) {
+            Some(_) => Some(self.positional(1, "day")?),
+            None => None,
+        };
+
+        Ok(registry::puzzles()
+            .iter()
+            .copied()
+            .filter(|p| year.is_none_or(|year| p.year == year))
+            .filter(|p| day.is_none_or(|day| p.day == day))
+            .collect())
+    }
+
+    ///
+    /// # input_loader
+    ///
+    /// ## Returns
+    ///
+    /// * `InputLoader` - A loader using `--data-dir`, or the environment when it isn't given
+    fn input_loader(&self) -> InputLoader {
+        match self.option("data-dir") {
+            Some(data_dir) => InputLoader::new(data_dir),
+            None => InputLoader::from_env(),
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # print_answer
+///
+/// Print the answer of a part. Answers spanning several lines start on their own line.
+///
+/// ## Arguments
+///
+/// * `part` - The part the answer belongs to
+/// * `answer` - The answer
+fn print_answer(part: Part, answer: &Answer) {
+    let answer = answer.to_string();
+
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+///
+/// # print_implemented_days
+///
+/// Print the days implemented for a year, to help with an unknown day.
+///
+/// ## Arguments
+///
+/// * `year` - The year to list
+fn print_implemented_days(year: u16) {
+    let days: Vec<String> = registry::of_year(year)
+        .map(|puzzle| puzzle.day.to_string())
+        .collect();
+
+    if days.is_empty() {
+        let years: Vec<String> = registry::years().iter().map(u16::to_string).collect();
+        eprintln!(
+            "No puzzle is implemented for {}. Implemented years: {}",
+            year,
+            years.join(", ")
+        );
+    } else {
+        eprintln!("Implemented days for {}: {}", year, days.join(", "));
+    }
+}
+
+///
+/// # run
+///
+/// `aoc run <year> <day> [--part <1|2>] [--visualize [--fps <n>]]`
+fn run(args: &Args) -> Result<ExitCode, String> {
+    if args.flag("all") {
+        return run_all(args);
+    }
+
+    let year: u16 = args.positional(0, "year")?;
+    let day: u8 = args.positional(1, "day")?;
+    let parts = match args.option("part") {
+        Some(part) => vec![part.parse::<Part>()?],
+        None => Part::ALL.to_vec(),
+    };
+
+    let Some(puzzle) = registry::find(year, day) else {
+        eprintln!("{} day {} is not implemented.", year, day);
+        print_implemented_days(year);
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let input = args
+        .input_loader()
+        .load(year, day)
+        .map_err(|error| error.to_string())?;
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("error: invalid input for {} day {}, {}", year, day, error);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    if args.flag("visualize") {
+        let Some(mut simulation) = puzzle.visualize(parsed.as_ref()) else {
+            eprintln!("{} day {} has no visualization.", year, day);
+            return Ok(ExitCode::FAILURE);
+        };
+
+        let mut options = VisualizeOptions::from_env();
+        if let Some(fps) = args.option("fps") {
+            options.frame_rate = parse_count(fps, "fps")?;
+        }
+        visualize::animate(simulation.as_mut(), &options, io::stdout().lock())
+            .map_err(|error| error.to_string())?;
+    }
+
+    println!("{} - Day {:02}", year, day);
+    for part in parts {
+        print_answer(part, &puzzle.solve_parsed(parsed.as_ref(), part));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+///
+/// # run_all
+///
+/// `aoc run --all [--year <year>] [--threads <n>]`
+///
+/// Solve every puzzle, or those of a year, on a pool of threads and print a summary of their
+/// answers. Fails if any puzzle panics, rejects its input or differs from the answers file.
+fn run_all(args: &Args) -> Result<ExitCode, String> {
+    let year: Option<u16> = match args.option("year") {
+        Some(year) => Some(
+            year.parse()
+                .map_err(|_| format!("invalid value `{}` for `--year`", year))?,
+        ),
+        None => None,
+    };
+    let threads = match args.option("threads") {
+        Some(threads) => parse_count(threads, "threads")?,
+        None => 0,
+    };
+
+    let puzzles: Vec<&Puzzle> = registry::puzzles()
+        .iter()
+        .copied()
+        .filter(|p| year.is_none_or(|year| p.year == year))
+        .collect();
+    if let (Some(year), true) = (year, puzzles.is_empty()) {
+        print_implemented_days(year);
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let loader = args.input_loader();
+    let store =
+        AnswerStore::load(&AnswerStore::path(loader.data_dir())).map_err(|e| e.to_string())?;
+
+    // The panics are reported in the summary, with their message
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let runs = runner::run_all(&puzzles, &loader, &store, threads);
+    panic::set_hook(hook);
+
+    print!("{}", runner::summary_table(&runs));
+
+    if runs.iter().any(|run| run.failed()) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+///
+/// # verify
+///
+/// `aoc verify [<year> [<day>]]`
+///
+/// Solve every implemented part and compare it with the answers file. Fails if any answer
+/// differs from the stored one.
+fn verify(args: &Args) -> Result<ExitCode, String> {
+    let puzzles = args.puzzles()?;
+    let loader = args.input_loader();
+    let store =
+        AnswerStore::load(&AnswerStore::path(loader.data_dir())).map_err(|e| e.to_string())?;
+    let (mut passed, mut failed, mut missing, mut skipped) = (0, 0, 0, 0);
+
+    for puzzle in puzzles {
+        let input = match loader.load(puzzle.year, puzzle.day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!(
+                    "{} - Day {:02}: skipped, {}",
+                    puzzle.year, puzzle.day, error
+                );
+                skipped += 1;
+                continue;
+            }
+        };
+        let parsed = match puzzle.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                println!(
+                    "{} - Day {:02}: FAIL, invalid input, {}",
+                    puzzle.year, puzzle.day, error
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let answer = puzzle.solve_parsed(parsed.as_ref(), part);
+            let label = format!("{} - Day {:02} - Part {}", puzzle.year, puzzle.day, part);
+
+            // A part that isn't implemented yet has nothing to verify.
+            if answer == Answer::Unsolved && store.get(puzzle.year, puzzle.day, part).is_none() {
+                continue;
+            }
+
+            match store.check(puzzle.year, puzzle.day, part, &answer) {
+                Verdict::Pass => {
+                    println!("{}: pass", label);
+                    passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!(
+                        "{}: FAIL, got {} but expected {}",
+                        label,
+                        inline(&answer.to_string()),
+                        inline(&expected)
+                    );
+                    failed += 1;
+                }
+                Verdict::Missing => {
+                    println!("{}: missing, got {}", label, inline(&answer.to_string()));
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing, {} skipped",
+        passed, failed, missing, skipped
+    );
+
+    if failed > 0 {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+///
+/// # bench
+///
+/// `aoc bench [<year> [<day>]] [--iterations <n>] [--warmup <n>] [--markdown]`
+///
+/// Time the parsing and both parts of every selected puzzle having an input.
+fn bench(args: &Args) -> Result<ExitCode, String> {
+    let puzzles = args.puzzles()?;
+    let loader = args.input_loader();
+    let mut options = BenchOptions::default();
+    if let Some(iterations) = args.option("iterations") {
+        options.iterations = parse_count(iterations, "iterations")?;
+    }
+    if let Some(warmup) = args.option("warmup") {
+        options.warmup = parse_count(warmup, "warmup")?;
+    }
+
+    let markdown = args.flag("markdown");
+    let mut benches = Vec::new();
+
+    for puzzle in puzzles {
+        let input = match loader.load(puzzle.year, puzzle.day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!(
+                    "{} - Day {:02}: skipped, {}",
+                    puzzle.year, puzzle.day, error
+                );
+                continue;
+            }
+        };
+        let result = match bench::bench(puzzle, &input, &options) {
+            Ok(result) => result,
+            Err(error) => {
+                eprintln!(
+                    "{} - Day {:02}: skipped, invalid input, {}",
+                    puzzle.year, puzzle.day, error
+                );
+                continue;
+            }
+        };
+
+        if !markdown {
+            println!("{} - Day {:02}", result.year, result.day);
+            println!("  Parse:  {}", bench::format_stats(Some(&result.parse)));
+            println!(
+                "  Part 1: {}",
+                bench::format_stats(result.part_one.as_ref())
+            );
+            println!(
+                "  Part 2: {}",
+                bench::format_stats(result.part_two.as_ref())
+            );
+        }
+
+        benches.push(result);
+    }
+
+    if markdown {
+        print!("{}", bench::markdown_table(&benches));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+///
+/// # parse_count
+///
+/// ## Arguments
+///
+/// * `value` - The value of the option
+/// * `name` - The name of the option, for error messages
+///
+/// ## Returns
+///
+/// * `Result<T, String>` - The parsed count, an error if it is out of the range of `T`
+fn parse_count<T: FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{}` for `--{}`", value, name))
+}
+
+///
+/// # inline
+///
+/// ## Arguments
+///
+/// * `answer` - A displayed answer
+///
+/// ## Returns
+///
+/// * `String` - The answer, quoted and escaped if it spans several lines
+fn inline(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("{:?}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+///
+/// # export
+///
+/// `aoc export <year> <day> [--format <png|ppm|gif>] [--output <dir>] [--scale <n>] [--every <n>]
+/// [--fps <n>]`
+///
+/// Save the frames of the visualization of a puzzle to `<output>/<year>_day_<day>/`, or to
+/// `<output>/<year>_day_<day>.gif`.
+fn export(args: &Args) -> Result<ExitCode, String> {
+    let year: u16 = args.positional(0, "year")?;
+    let day: u8 = args.positional(1, "day")?;
+    let format = args.option("format").unwrap_or("png");
+    let output = PathBuf::from(args.option("output").unwrap_or("output"));
+
+    let mut options = ExportOptions::default();
+    if let Some(scale) = args.option("scale") {
+        options.scale = parse_count(scale, "scale")?;
+    }
+    if let Some(every) = args.option("every") {
+        options.every = parse_count(every, "every")?;
+    }
+    if let Some(fps) = args.option("fps") {
+        options.frame_rate = parse_count(fps, "fps")?;
+    }
+
+    let Some(puzzle) = registry::find(year, day) else {
+        eprintln!("{} day {} is not implemented.", year, day);
+        print_implemented_days(year);
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let input = args
+        .input_loader()
+        .load(year, day)
+        .map_err(|error| error.to_string())?;
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("error: invalid input for {} day {}, {}", year, day, error);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let Some(mut simulation) = puzzle.visualize(parsed.as_ref()) else {
+        eprintln!("{} day {} has no visualization.", year, day);
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let name = format!("{}_day_{:02}", year, day);
+    let (frames, destination) = if format == "gif" {
+        let path = output.join(format!("{}.gif", name));
+        fs::create_dir_all(&output).map_err(|error| error.to_string())?;
+        let file = File::create(&path).map_err(|error| error.to_string())?;
+        let frames = image::export_gif(simulation.as_mut(), BufWriter::new(file), &options)
+            .map_err(|error| error.to_string())?;
+        (frames, path)
+    } else {
+        let format: ImageFormat = format.parse()?;
+        let directory = output.join(name);
+        let paths = image::export_frames(simulation.as_mut(), &directory, format, &options)
+            .map_err(|error| error.to_string())?;
+        (paths.len(), directory)
+    };
+
+    println!("{} frames written to {}", frames, destination.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+///
+/// # fetch
+///
+/// `aoc fetch <year> <day>`
+///
+/// Download the input of a puzzle into the data directory. A cached input is never requested
+/// again.
+fn fetch(args: &Args) -> Result<ExitCode, String> {
+    let year: u16 = args.positional(0, "year")?;
+    let day: u8 = args.positional(1, "day")?;
+
+    let config = ClientConfig::load().map_err(|error| error.to_string())?;
+    let client = Client::new(config);
+
+    match client::fetch_input(&client, &args.input_loader(), year, day) {
+        Ok((path, true)) => println!("Downloaded {} day {} to {}", year, day, path.display()),
+        Ok((path, false)) => println!(
+            "{} day {} is already cached at {}",
+            year,
+            day,
+            path.display()
+        ),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+///
+/// # submit
+///
+/// `aoc submit <year> <day> <part>`
+///
+/// Solve a part of a puzzle and send its answer to the website. The answers already known to be
+/// wrong are refused before sending them, see [`submit::GuessHistory::check`].
+fn submit(args: &Args) -> Result<ExitCode, String> {
+    let year: u16 = args.positional(0, "year")?;
+    let day: u8 = args.positional(1, "day")?;
+    let part: Part = args.positional(2, "part")?;
+
+    let Some(puzzle) = registry::find(year, day) else {
+        eprintln!("{} day {} is not implemented.", year, day);
+        print_implemented_days(year);
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let loader = args.input_loader();
+    let input = loader.load(year, day).map_err(|error| error.to_string())?;
+    let parsed = match puzzle.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("error: invalid input for {} day {}, {}", year, day, error);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let answer = puzzle.solve_parsed(parsed.as_ref(), part);
+
+    let config = ClientConfig::load().map_err(|error| error.to_string())?;
+    let client = Client::new(config);
+    let mut history = GuessHistory::load(&GuessHistory::path(loader.data_dir()))
+        .map_err(|error| error.to_string())?;
+
+    println!("{} - Day {:02} - Part {}: {}", year, day, part, answer);
+    match submit::submit(&client, &mut history, year, day, part, &answer) {
+        Ok(feedback) => {
+            println!("{}", feedback);
+            if feedback == Feedback::Correct {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+///
+/// # new
+///
+/// `aoc new <year> <day>`
+///
+/// Create a new day in the repository of the working directory. Refuses to touch an existing
+/// day.
+fn new(args: &Args) -> Result<ExitCode, String> {
+    let year: u16 = args.positional(0, "year")?;
+    let day: u8 = args.positional(1, "day")?;
+    let root = env::current_dir().map_err(|error| error.to_string())?;
+
+    match scaffold::scaffold(&root, year, day) {
+        Ok(paths) => {
+            println!("Created {} day {}:", year, day);
+            for path in paths {
+                println!("  {}", path.strip_prefix(&root).unwrap_or(&path).display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+///
+/// # list
+///
+/// `aoc list [<year>]`
+fn list(args: &Args) -> Result<ExitCode, String> {
+    let years = match args.positionals.get(1) {
+        Some(_) => vec![args.positional(0, "year")?],
+        None => registry::years(),
+    };
+
+    for year in years {
+        let days: Vec<String> = registry::of_year(year)
+            .map(|puzzle| format!("{:02}", puzzle.day))
+            .collect();
+
+        if days.is_empty() {
+            println!("{}: no puzzle implemented", year);
+        } else {
+            println!("{}: {}", year, days.join(" "));
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse(env::args().skip(1)).and_then(|args| match args.command() {
+        Some("run") => run(&args),
+        Some("verify") => verify(&args),
+        Some("bench") => bench(&args),
+        Some("export") => export(&args),
+        Some("new") => new(&args),
+        Some("fetch") => fetch(&args),
+        Some("submit") => submit(&args),
+        Some("list") => list(&args),
+        Some("help") | None => {
+            println!("{}", USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(command) => Err(format!("unknown command `{}`", command)),
+    });
+
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
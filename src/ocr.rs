@@ -0,0 +1,256 @@
+//!
+//! # ocr
+//! Recognition of the block letters drawn by some puzzles, like the CRT of 2022 day 10.
+//!
+//! Advent of Code draws its letters with two fonts: 4 columns by 6 rows, and 6 columns by
+//! 10 rows. The letters are separated by at least one unlit column, so they are cut at the
+//! unlit columns and each one is compared with the font of the height of the screen.
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::error::AocError;
+use crate::grid::Grid;
+
+// Variables  =========================================================================== Variables
+/// The known letters of the 6 rows tall font.
+const SMALL_FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The known letters of the 10 rows tall font.
+const LARGE_FONT: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+// Functions  =========================================================================== Functions
+///
+/// # recognize
+///
+/// Read the letters drawn on a screen.
+///
+/// ## Arguments
+///
+/// * `screen` - The lit and unlit cells, 6 or 10 rows tall
+///
+/// ## Returns
+///
+/// * `Result<String, AocError>` - The letters from left to right, or an error naming the column
+///   of the first letter that isn't recognized
+pub fn recognize(screen: &Grid<bool>) -> Result<String, AocError> {
+    let font: &[(char, &str)] = match screen.height() {
+        6 => &SMALL_FONT,
+        10 => &LARGE_FONT,
+        height => {
+            return Err(AocError::new(format!(
+                "no font is {} rows tall, expected 6 or 10",
+                height
+            )))
+        }
+    };
+
+    let lit_columns: Vec<bool> = (0..screen.width())
+        .map(|x| screen.column(x).any(|&lit| lit))
+        .collect();
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < screen.width() {
+        if !lit_columns[x] {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < screen.width() && lit_columns[x] {
+            x += 1;
+        }
+
+        let glyph = glyph(screen, start, x);
+        let letter = font
+            .iter()
+            .find(|(_, pattern)| trim_columns(pattern) == glyph)
+            .map(|&(letter, _)| letter)
+            .ok_or_else(|| {
+                AocError::new(format!(
+                    "unknown letter at column {}:\n{}",
+                    start + 1,
+                    glyph
+                ))
+            })?;
+        letters.push(letter);
+    }
+
+    Ok(letters)
+}
+
+///
+/// # glyph
+///
+/// ## Arguments
+///
+/// * `screen` - The lit and unlit cells
+/// * `start` - The first column of the letter
+/// * `end` - The column after the letter
+///
+/// ## Returns
+///
+/// * `String` - The letter drawn with `#` and `.`, in the format of the fonts
+fn glyph(screen: &Grid<bool>, start: usize, end: usize) -> String {
+    screen
+        .rows()
+        .map(|row| {
+            row[start..end]
+                .iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+///
+/// # trim_columns
+///
+/// ## Arguments
+///
+/// * `pattern` - A letter of a font
+///
+/// ## Returns
+///
+/// * `String` - The letter without its unlit columns on the left and right, as cut from a screen
+fn trim_columns(pattern: &str) -> String {
+    let rows: Vec<&str> = pattern.lines().collect();
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| lit(x)).unwrap_or(width);
+    let end = (0..width).rfind(|&x| lit(x)).map_or(start, |x| x + 1);
+
+    rows.iter()
+        .map(|row| &row[start..end])
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(drawing: &str) -> Grid<bool> {
+        Grid::parse(drawing, |c, _| Ok(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_recognize_small_font() {
+        let drawing = "\
+#..#.###..####.#....###..#...#
+#..#..#...#....#....#..#.#...#
+####..#...###..#....#..#..#.#.
+#..#..#...#....#....###....#..
+#..#..#...#....#....#......#..
+#..#.###..####.####.#......#..";
+
+        assert_eq!(recognize(&screen(drawing)).unwrap(), "HIELPY");
+    }
+
+    #[test]
+    fn test_recognize_large_font() {
+        // The letters A, B and C next to each other, two columns apart
+        let mut rows = vec![String::new(); 10];
+        for (_, pattern) in &LARGE_FONT[..3] {
+            for (row, part) in rows.iter_mut().zip(pattern.lines()) {
+                row.push_str(part);
+                row.push_str("..");
+            }
+        }
+        let drawing = rows.join("\n");
+
+        assert_eq!(recognize(&screen(&drawing)).unwrap(), "ABC");
+    }
+
+    #[test]
+    fn test_recognize_errors() {
+        let error = recognize(&screen("#\n#\n#")).unwrap_err();
+        assert!(error.message().contains("3 rows tall"));
+
+        let error =
+            recognize(&screen("##..#.\n#...#.\n#...#.\n#...#.\n#...#.\n#...##")).unwrap_err();
+        assert!(error.message().starts_with("unknown letter at column 1"));
+    }
+}
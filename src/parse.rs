@@ -0,0 +1,603 @@
+//!
+//! # parse
+//! Helpers to parse puzzle inputs, attaching the position of any problem to the error.
+//!
+//! Most lines are read with a template of the [`scan!`](crate::scan) macro, whose `{}` are
+//! parsed to the types given after it with the [`Field`] trait:
+//!
+//! ```
+//! use aoc::scan;
+//!
+//! let line = "Valve BB has flow rate=13; tunnels lead to valves CC, AA";
+//! let (name, rate, tunnels) = scan!(line, "Valve {} has flow rate={}; {}" => &str, u32, &str)?;
+//!
+//! assert_eq!((name, rate), ("BB", 13));
+//! assert_eq!(tunnels, "tunnels lead to valves CC, AA");
+//! # Ok::<(), aoc::error::AocError>(())
+//! ```
+//!
+
+// Imports  ==============================================================================  Imports
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// The derive of `FromStr` from a pattern, see [`aoc_derive`].
+pub use aoc_derive::FromInput;
+
+// Variables  =========================================================================== Variables
+///
+/// # Field
+///
+/// A value read from a piece of text: a placeholder of a [`scan!`](crate::scan) template, an
+/// item of a [`list`].
+pub trait Field<'a>: Sized {
+    ///
+    /// # field
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` - The text of the value
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Self, AocError>` - The value, or an error with a column relative to `token`
+    ///   (without a column, the error points at the start of `token`)
+    fn field(token: &'a str) -> Result<Self, AocError>;
+}
+
+// The numbers are trimmed, and their errors name the token
+macro_rules! impl_field_for_numbers {
+    ($($t:ty),*) => {
+        $(
+            impl Field<'_> for $t {
+                fn field(token: &str) -> Result<Self, AocError> {
+                    token
+                        .trim()
+                        .parse()
+                        .map_err(|_| AocError::new(format!("invalid number `{}`", token.trim())))
+                }
+            }
+        )*
+    };
+}
+
+impl_field_for_numbers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<'a> Field<'a> for &'a str {
+    fn field(token: &'a str) -> Result<Self, AocError> {
+        Ok(token)
+    }
+}
+
+impl Field<'_> for String {
+    fn field(token: &str) -> Result<Self, AocError> {
+        Ok(token.to_string())
+    }
+}
+
+impl Field<'_> for char {
+    fn field(token: &str) -> Result<Self, AocError> {
+        let mut chars = token.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(AocError::new(format!(
+                "expected a single character, found `{}`",
+                token
+            ))),
+        }
+    }
+}
+
+///
+/// # scan
+///
+/// Read the values of a line, or of a block of lines, following a template where each `{}` is
+/// a value. The text around the placeholders must be found as is; a placeholder takes the text
+/// up to the first occurrence of what follows it, or the rest of the text if it ends the
+/// template. Two placeholders must be separated by some text.
+///
+/// ## Arguments
+///
+/// * `text` - The text to read
+/// * `template` - A string literal, like `"move {} from {} to {}"`
+/// * The types of the values, after `=>`, one per placeholder, each implementing [`Field`]
+///
+/// ## Returns
+///
+/// * `Result<(T, ...), AocError>` - The values, or the first problem with its column (and its
+///   line, for a text of many lines)
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $template:literal => $($field:ty),+ $(,)?) => {{
+        let text: &str = $text;
+        $crate::parse::captures(text, $template).and_then(|captures| {
+            let mut captures = captures.into_iter();
+            Ok(($(
+                $crate::parse::field::<$field>(
+                    text,
+                    captures.next().expect("more types than placeholders in the template"),
+                )?,
+            )+))
+        })
+    }};
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # lines
+///
+/// Parse every line of the input.
+///
+/// ## Arguments
+///
+/// * `input` - The input
+///
+/// ## Returns
+///
+/// * `Result<Vec<T>, AocError>` - The parsed lines, or the first error with its line
+pub fn lines<T: FromStr<Err = AocError>>(input: &str) -> Result<Vec<T>, AocError> {
+    lines_with(input, str::parse)
+}
+
+///
+/// # lines_with
+///
+/// Parse every line of the input with a function.
+///
+/// ## Arguments
+///
+/// * `input` - The input
+/// * `parse` - The parser of a line
+///
+/// ## Returns
+///
+/// * `Result<Vec<T>, AocError>` - The parsed lines, or the first error with its line
+pub fn lines_with<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.at_line(index + 1, line)))
+        .collect()
+}
+
+///
+/// # blocks
+///
+/// Parse every block of lines of the input, the blocks being separated by blank lines.
+/// The lines of the errors returned by `parse` are relative to the block.
+///
+/// ## Arguments
+///
+/// * `input` - The input
+/// * `parse` - The parser of a block
+///
+/// ## Returns
+///
+/// * `Result<Vec<T>, AocError>` - The parsed blocks, or the first error with its line
+pub fn blocks<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    let mut parsed = Vec::new();
+    let mut offset = 0;
+
+    for block in input.split("\n\n") {
+        let first_line = block.lines().next().unwrap_or_default();
+        let value = parse(block)
+            .map_err(|error| error.offset_lines(offset).at_line(offset + 1, first_line))?;
+
+        parsed.push(value);
+        offset += block.lines().count() + 1;
+    }
+
+    Ok(parsed)
+}
+
+///
+/// # number
+///
+/// Parse a number, pointing at it on error.
+///
+/// ## Arguments
+///
+/// * `text` - The text containing the number, starting at column 1
+/// * `token` - The number, a slice of `text`
+///
+/// ## Returns
+///
+/// * `Result<T, AocError>` - The parsed number
+pub fn number<T: FromStr>(text: &str, token: &str) -> Result<T, AocError> {
+    token.trim().parse().map_err(|_| {
+        AocError::new(format!("invalid number `{}`", token.trim())).at_token(text, token)
+    })
+}
+
+///
+/// # integers
+///
+/// Read every integer of a text, ignoring what is around them. A `-` is the sign of the
+/// integer after it, unless it follows a letter or a digit, as in `2-4` or `x-3`.
+///
+/// ## Arguments
+///
+/// * `text` - The text containing the integers, starting at column 1
+///
+/// ## Returns
+///
+/// * `Result<Vec<T>, AocError>` - The integers from left to right, or the first one that doesn't
+///   fit in `T`
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, AocError> {
+    let bytes = text.as_bytes();
+    let mut integers = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let signed = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+
+        if !signed && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        integers.push(number(text, &text[start..index])?);
+    }
+
+    Ok(integers)
+}
+
+///
+/// # list
+///
+/// Read the items of a list, like `79, 98` or `DD, II, BB`.
+///
+/// ## Arguments
+///
+/// * `text` - The text containing the list, starting at column 1
+/// * `token` - The list, a slice of `text`
+/// * `separator` - The text between two items
+///
+/// ## Returns
+///
+/// * `Result<Vec<T>, AocError>` - The items, or the first error with its column in `text`
+pub fn list<'a, T: Field<'a>>(
+    text: &'a str,
+    token: &'a str,
+    separator: &str,
+) -> Result<Vec<T>, AocError> {
+    token
+        .split(separator)
+        .map(|item| field(text, item))
+        .collect()
+}
+
+///
+/// # field
+///
+/// Read a value of a text, pointing at it on error. Used by [`scan!`](crate::scan).
+///
+/// ## Arguments
+///
+/// * `text` - The text containing the value, starting at line 1 and column 1
+/// * `token` - The value, a slice of `text`
+///
+/// ## Returns
+///
+/// * `Result<T, AocError>` - The value
+pub fn field<'a, T: Field<'a>>(text: &'a str, token: &'a str) -> Result<T, AocError> {
+    T::field(token).map_err(|error| locate(error, text, token))
+}
+
+///
+/// # captures
+///
+/// Match a text with a template, see [`scan!`](crate::scan).
+///
+/// ## Arguments
+///
+/// * `text` - The text to read, starting at line 1 and column 1
+/// * `template` - The template, with a `{}` for each value
+///
+/// ## Returns
+///
+/// * `Result<Vec<&str>, AocError>` - The text of each placeholder, or where the text differs
+///   from the template
+pub fn captures<'a>(text: &'a str, template: &str) -> Result<Vec<&'a str>, AocError> {
+    let mut pieces = template.split("{}");
+    let mut captures = Vec::new();
+    let mut position = expect(text, 0, pieces.next().unwrap_or_default())?;
+
+    for piece in pieces {
+        let rest = &text[position..];
+
+        if piece.is_empty() {
+            // The last placeholder takes the rest of the text
+            captures.push(rest);
+            position = text.len();
+            continue;
+        }
+
+        // Without the text after the placeholder, the mismatch is searched where it starts
+        let end = rest
+            .find(piece)
+            .or_else(|| rest.find(piece.chars().next()?))
+            .unwrap_or(rest.len());
+        captures.push(&rest[..end]);
+        position = expect(text, position + end, piece)?;
+    }
+
+    if position < text.len() {
+        let rest = &text[position..];
+        return Err(locate(
+            AocError::new(format!(
+                "unexpected `{}`",
+                rest.lines().next().unwrap_or(rest)
+            )),
+            text,
+            rest,
+        ));
+    }
+
+    Ok(captures)
+}
+
+///
+/// # furthest
+///
+/// Choose the error to report when no pattern matches a text: the one found the furthest in the
+/// text, or a list of the patterns when they all differ from its start. Used by the enums
+/// deriving [`FromInput`].
+///
+/// ## Arguments
+///
+/// * `errors` - The error of each pattern
+/// * `patterns` - The patterns, as written
+///
+/// ## Returns
+///
+/// * `AocError` - The error to report
+pub fn furthest(errors: Vec<AocError>, patterns: &[&str]) -> AocError {
+    let position = |error: &AocError| (error.line().unwrap_or(0), error.column().unwrap_or(0));
+
+    // The first of the errors found the furthest
+    match errors.into_iter().rev().max_by_key(position) {
+        Some(error) if error.line() > Some(1) || error.column() > Some(1) => error,
+        _ => {
+            let patterns: Vec<String> = patterns
+                .iter()
+                .map(|pattern| format!("`{}`", pattern))
+                .collect();
+            let expected = match patterns.split_last() {
+                Some((last, [])) => last.clone(),
+                Some((last, others)) => format!("{} or {}", others.join(", "), last),
+                None => "nothing".to_string(),
+            };
+            AocError::new(format!("expected {}", expected)).at_column(1)
+        }
+    }
+}
+
+///
+/// # expect
+///
+/// ## Arguments
+///
+/// * `text` - The text to read
+/// * `position` - Where the expected text should be, a byte offset in `text`
+/// * `expected` - The expected text
+///
+/// ## Returns
+///
+/// * `Result<usize, AocError>` - The position after the expected text, or an error pointing at
+///   the first character that differs
+fn expect(text: &str, position: usize, expected: &str) -> Result<usize, AocError> {
+    let rest = &text[position..];
+    if rest.starts_with(expected) {
+        return Ok(position + expected.len());
+    }
+
+    let (offset, expected_offset) = rest
+        .char_indices()
+        .zip(expected.char_indices())
+        .find(|((_, found), (_, wanted))| found != wanted)
+        .map_or(
+            (rest.len(), expected.len().min(rest.len())),
+            |((a, _), (b, _))| (a, b),
+        );
+
+    // Name the line of the template that differs, without the indentation
+    let line_start = expected[..expected_offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = expected[line_start..]
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim();
+    let message = if line.is_empty() {
+        "expected a new line".to_string()
+    } else {
+        format!("expected `{}`", line)
+    };
+
+    Err(locate(AocError::new(message), text, &rest[offset..]))
+}
+
+///
+/// # locate
+///
+/// Attach the position of a token to an error, the column of the error being relative to the
+/// token. The line is attached too when the text has many lines.
+///
+/// ## Arguments
+///
+/// * `error` - The error found by reading `token`
+/// * `text` - The whole text, starting at line 1 and column 1
+/// * `token` - The part of `text` that is wrong
+///
+/// ## Returns
+///
+/// * `AocError` - The error, with its position in `text`, unchanged if `token` isn't a part of
+///   `text`
+fn locate(error: AocError, text: &str, token: &str) -> AocError {
+    let Some(offset) = (token.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|&offset| offset <= text.len() && token.len() <= text.len() - offset)
+    else {
+        return error;
+    };
+    let start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+    let line = &text[start..end];
+    let error = error.within(line, &text[offset..offset.max(end)]);
+
+    if start == 0 && end == text.len() {
+        error
+    } else {
+        error.at_line(text[..start].matches('\n').count() + 1, line)
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let parsed: Vec<u32> = lines_with("1\n22\n333", |line| number(line, line)).unwrap();
+        assert_eq!(parsed, vec![1, 22, 333]);
+
+        let error = lines_with("1\n2x\n3", |line| number::<u32>(line, line)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: invalid number `2x`\n    2x\n    ^"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let parse = |block: &str| lines_with(block, |line| number::<u32>(line, line));
+
+        assert_eq!(
+            blocks("1\n2\n\n3\n\n4\n5", parse).unwrap(),
+            vec![vec![1, 2], vec![3], vec![4, 5]]
+        );
+
+        let error = blocks("1\n2\n\n3\n\n4\nx", parse).unwrap_err();
+        assert_eq!(error.line(), Some(7));
+        assert_eq!(error.column(), Some(1));
+    }
+
+    #[test]
+    fn test_field_of_another_text() {
+        let owned = String::from("x");
+        let error = field::<u32>("12 x", &owned).unwrap_err();
+
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!((error.line(), error.column()), (None, None));
+
+        let error = list::<u32>("1, 2", "1, x", ", ").unwrap_err();
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!(error.line(), None);
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(
+            integers::<i32>("Sensor at x=2, y=-18: beacon at x=-2, y=15"),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(integers::<u32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(integers::<u8>("no number - here"), Ok(vec![]));
+
+        let error = integers::<u8>("1, 2, 300").unwrap_err();
+        assert_eq!(error.message(), "invalid number `300`");
+        assert_eq!(error.column(), Some(7));
+    }
+
+    #[test]
+    fn test_scan() {
+        let line = "move 3 from 1 to 2";
+        assert_eq!(
+            scan!(line, "move {} from {} to {}" => u8, usize, char),
+            Ok((3, 1, '2'))
+        );
+        assert_eq!(
+            scan!("x=-4, name", "x={}, {}" => i64, String),
+            Ok((-4, "name".to_string()))
+        );
+
+        let error = scan!("move 3 form 1 to 2", "move {} from {} to {}" => u8, u8, u8).unwrap_err();
+        assert_eq!(error.message(), "expected `from`");
+        assert_eq!(error.column(), Some(9));
+
+        let error = scan!("move 3 from x to 2", "move {} from {} to {}" => u8, u8, u8).unwrap_err();
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!(error.column(), Some(13));
+
+        let error = scan!("Test: 19 and more", "Test: {} and" => u8).unwrap_err();
+        assert_eq!(error.message(), "unexpected ` more`");
+        assert_eq!(error.column(), Some(13));
+    }
+
+    #[test]
+    fn test_scan_lines() {
+        let block = "Monkey 0:\n  Items: 79, 98\n  Test: divisible by 19";
+        let (id, items, test) =
+            scan!(block, "Monkey {}:\n  Items: {}\n  Test: divisible by {}" => u8, &str, u64)
+                .unwrap();
+
+        assert_eq!((id, test), (0, 19));
+        assert_eq!(list::<u64>(block, items, ", "), Ok(vec![79, 98]));
+
+        let block = "Monkey 0:\n  Items: 79, x8";
+        let (_, items) = scan!(block, "Monkey {}:\n  Items: {}" => u8, &str).unwrap();
+        let error = list::<u64>(block, items, ", ").unwrap_err();
+        assert_eq!(error.message(), "invalid number `x8`");
+        assert_eq!((error.line(), error.column()), (Some(2), Some(14)));
+
+        let error = scan!(
+            "Monkey 0:\n  Items: 79\n  Test: divisble by 19",
+            "Monkey {}:\n  Items: {}\n  Test: divisible by {}" => u8, &str, u64
+        )
+        .unwrap_err();
+        assert_eq!(error.message(), "expected `Test: divisible by`");
+        assert_eq!((error.line(), error.column()), (Some(3), Some(14)));
+    }
+
+    #[test]
+    fn test_furthest() {
+        let errors = vec![
+            AocError::new("expected `noop`").at_column(1),
+            AocError::new("invalid number `y`").at_column(6),
+            AocError::new("expected `move`").at_column(2),
+        ];
+        assert_eq!(furthest(errors, &[]).message(), "invalid number `y`");
+
+        let errors = vec![AocError::new("a").at_column(1), AocError::new("b")];
+        let error = furthest(errors, &["noop", "addx {0}"]);
+        assert_eq!(error.message(), "expected `noop` or `addx {0}`");
+        assert_eq!(error.column(), Some(1));
+    }
+
+    #[test]
+    fn test_number() {
+        let line = "move 3 from x to 2";
+
+        assert_eq!(number::<u8>(line, &line[5..6]), Ok(3));
+
+        let error = number::<u8>(line, &line[12..13]).unwrap_err();
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!(error.column(), Some(13));
+    }
+}
@@ -1,40 +1,349 @@
+//!
+//! # point
+//! A generic 2D point, usable with any of the built-in numeric types.
+//!
+
+// Imports  ==============================================================================  Imports
 use std::fmt;
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::parse;
 
-// Define a trait that combines the necessary numeric traits
+// Variables  =========================================================================== Variables
+///
+/// # Number
+///
+/// The numeric operations a coordinate of a [`Point`] must support.
+/// It is implemented for every built-in integer and floating point type.
 pub trait Number:
-    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + fmt::Display
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + fmt::Display
 {
+    ///
+    /// # zero
+    ///
+    /// ## Returns
+    ///
+    /// * `Self` - The additive identity
+    fn zero() -> Self;
+
+    ///
+    /// # one
+    ///
+    /// ## Returns
+    ///
+    /// * `Self` - The multiplicative identity
+    fn one() -> Self;
+
+    ///
+    /// # checked_add
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The number to add
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Self>` - The sum, `None` if it overflows
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    ///
+    /// # checked_sub
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The number to subtract
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Self>` - The difference, `None` if it overflows
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    ///
+    /// # abs_diff
+    ///
+    /// The difference is never computed below zero, so it works with unsigned types.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other number
+    ///
+    /// ## Returns
+    ///
+    /// * `Self` - `|self - other|`
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+// Implement the Number trait for the built-in integer types
+macro_rules! impl_number_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
 }
 
-// Implement the Number trait for the built-in numeric types
-impl Number for i8 {}
-impl Number for i16 {}
-impl Number for i32 {}
-impl Number for i64 {}
-impl Number for i128 {}
-impl Number for u8 {}
-impl Number for u16 {}
-impl Number for u32 {}
-impl Number for u64 {}
-impl Number for u128 {}
-impl Number for f32 {}
-impl Number for f64 {}
+// Implement the Number trait for the floating point types, which never overflow
+macro_rules! impl_number_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn zero() -> Self {
+                    0.0
+                }
 
+                fn one() -> Self {
+                    1.0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    Some(self - other)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number_for_floats!(f32, f64);
+
+///
+/// # Point
+///
+/// A point in a 2D space.
+/// As in the grids, `y` grows downwards: "up" is `y - 1` and a clockwise rotation turns right
+/// into down.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point<T: Number> {
     pub x: T,
     pub y: T,
 }
 
-// Most of the solutions will implement their own `FromStr` trait for the `Point` struct.
-#[allow(dead_code)]
 impl<T: Number> Point<T> {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The horizontal coordinate
+    /// * `y` - The vertical coordinate
+    ///
+    /// ## Returns
+    ///
+    /// * `Point<T>` - The point at `(x, y)`
     pub fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
+
+    ///
+    /// # origin
+    ///
+    /// ## Returns
+    ///
+    /// * `Point<T>` - The point at `(0, 0)`
+    pub fn origin() -> Self {
+        Point::new(T::zero(), T::zero())
+    }
+
+    ///
+    /// # manhattan_distance
+    ///
+    /// The distance between two points when only moving horizontally and vertically.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other point
+    ///
+    /// ## Returns
+    ///
+    /// * `T` - `|x1 - x2| + |y1 - y2|`
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    ///
+    /// # chebyshev_distance
+    ///
+    /// The distance between two points when moving diagonally costs as much as moving
+    /// horizontally or vertically, like a king on a chessboard.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other point
+    ///
+    /// ## Returns
+    ///
+    /// * `T` - `max(|x1 - x2|, |y1 - y2|)`
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    ///
+    /// # neighbors4
+    ///
+    /// The neighbours whose coordinates would overflow, like the left one of `x = 0` for an
+    /// unsigned point, are skipped.
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<T>>` - The points above, to the right, below and to the
+    ///   left of this one
+    pub fn neighbors4(&self) -> impl Iterator<Item = Self> {
+        let (x, y, one) = (self.x, self.y, T::one());
+
+        [
+            y.checked_sub(one).map(|y| Point::new(x, y)),
+            x.checked_add(one).map(|x| Point::new(x, y)),
+            y.checked_add(one).map(|y| Point::new(x, y)),
+            x.checked_sub(one).map(|x| Point::new(x, y)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    ///
+    /// # neighbors8
+    ///
+    /// The neighbours whose coordinates would overflow are skipped.
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = Point<T>>` - The points around this one, diagonals included,
+    ///   clockwise from the top-left one
+    pub fn neighbors8(&self) -> impl Iterator<Item = Self> {
+        let (x, y, one) = (self.x, self.y, T::one());
+        let (left, right) = (x.checked_sub(one), x.checked_add(one));
+        let (up, down) = (y.checked_sub(one), y.checked_add(one));
+        let point = |x: Option<T>, y: Option<T>| Some(Point::new(x?, y?));
+
+        [
+            point(left, up),
+            point(Some(x), up),
+            point(right, up),
+            point(right, Some(y)),
+            point(right, down),
+            point(Some(x), down),
+            point(left, down),
+            point(left, Some(y)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Point<T> {
+    ///
+    /// # rotate_clockwise
+    ///
+    /// Rotate the point by 90° around the origin.
+    ///
+    /// ## Returns
+    ///
+    /// * `Point<T>` - `(-y, x)`, so that right becomes down
+    pub fn rotate_clockwise(&self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    ///
+    /// # rotate_counterclockwise
+    ///
+    /// Rotate the point by -90° around the origin.
+    ///
+    /// ## Returns
+    ///
+    /// * `Point<T>` - `(y, -x)`, so that right becomes up
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+}
+
+// Offsets of the unsigned points by a signed point, since `Add` needs the same type on both sides
+macro_rules! impl_unsigned_offsets {
+    ($($unsigned:ty => $signed:ty),*) => {
+        $(
+            impl Point<$unsigned> {
+                ///
+                /// # checked_offset
+                ///
+                /// ## Arguments
+                ///
+                /// * `offset` - The signed offset to apply
+                ///
+                /// ## Returns
+                ///
+                /// * `Option<Point<T>>` - The moved point, `None` if a coordinate overflows
+                pub fn checked_offset(&self, offset: Point<$signed>) -> Option<Self> {
+                    Some(Point::new(
+                        self.x.checked_add_signed(offset.x)?,
+                        self.y.checked_add_signed(offset.y)?,
+                    ))
+                }
+
+                ///
+                /// # wrapping_offset
+                ///
+                /// A coordinate going below zero wraps around to a huge value, which is outside
+                /// of any grid.
+                ///
+                /// ## Arguments
+                ///
+                /// * `offset` - The signed offset to apply
+                ///
+                /// ## Returns
+                ///
+                /// * `Point<T>` - The moved point
+                pub fn wrapping_offset(&self, offset: Point<$signed>) -> Self {
+                    Point::new(
+                        self.x.wrapping_add_signed(offset.x),
+                        self.y.wrapping_add_signed(offset.y),
+                    )
+                }
+            }
+        )*
+    };
 }
 
+impl_unsigned_offsets!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
 impl<T: Number> Add for Point<T> {
     type Output = Self;
 
@@ -57,25 +366,189 @@ impl<T: Number> Sub for Point<T> {
     }
 }
 
+impl<T: Number> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Number> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Number> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Point {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+impl<T: Number> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, divisor: T) -> Self::Output {
+        Point {
+            x: self.x / divisor,
+            y: self.y / divisor,
+        }
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+// Parse the `x,y` format most puzzles write their points in
+impl<T: Number + FromStr> FromStr for Point<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| AocError::new("expected a point as `x,y`"))?;
+
+        Ok(Point {
+            x: parse::number(s, x)?,
+            y: parse::number(s, y)?,
+        })
+    }
+}
+
 impl<T: Number> fmt::Display for Point<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
 }
 
-#[allow(dead_code)]
-impl<T: Number> Point<T> {
-    pub fn manhattan_distance(&self, other: &Self) -> T {
-        let dx = if self.x > other.x {
-            self.x - other.x
-        } else {
-            other.x - self.x
-        };
-        let dy = if self.y > other.y {
-            self.y - other.y
-        } else {
-            other.y - self.y
-        };
-        dx + dy
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_and_sub() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a + b - b, a);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -3);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 7);
+        assert_eq!(a.manhattan_distance(&a), 0);
+    }
+
+    #[test]
+    fn test_manhattan_distance_unsigned() {
+        let a: Point<usize> = Point::new(2, 10);
+        let b: Point<usize> = Point::new(7, 4);
+
+        assert_eq!(a.manhattan_distance(&b), 11);
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        let a = Point::new(1, 1);
+
+        assert_eq!(a.chebyshev_distance(&Point::new(4, -3)), 4);
+        assert_eq!(a.chebyshev_distance(&Point::new(2, 2)), 1);
+        assert_eq!(Point::new(3u8, 0).chebyshev_distance(&Point::new(0, 1)), 3);
+    }
+
+    #[test]
+    fn test_scalar_arithmetic() {
+        let mut a = Point::new(3, -2);
+
+        assert_eq!(a * 2, Point::new(6, -4));
+        assert_eq!(a / 2, Point::new(1, -1));
+        assert_eq!(-a, Point::new(-3, 2));
+
+        a += Point::new(1, 1);
+        assert_eq!(a, Point::new(4, -1));
+        a -= Point::new(4, -1);
+        assert_eq!(a, Point::origin());
+    }
+
+    #[test]
+    fn test_offsets() {
+        let a: Point<usize> = Point::new(0, 2);
+
+        assert_eq!(a.checked_offset(Point::new(1, -2)), Some(Point::new(1, 0)));
+        assert_eq!(a.checked_offset(Point::new(-1, 0)), None);
+        assert_eq!(
+            a.wrapping_offset(Point::new(-1, 0)),
+            Point::new(usize::MAX, 2)
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let a = Point::new(0, 0);
+
+        assert_eq!(
+            a.neighbors4().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(a.neighbors8().count(), 8);
+
+        let corner: Point<usize> = Point::origin();
+        assert_eq!(corner.neighbors4().count(), 2);
+        assert_eq!(corner.neighbors8().count(), 3);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let right = Point::new(1, 0);
+
+        assert_eq!(right.rotate_clockwise(), Point::new(0, 1));
+        assert_eq!(right.rotate_counterclockwise(), Point::new(0, -1));
+        assert_eq!(
+            right
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            right
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("498,4".parse::<Point<i32>>(), Ok(Point::new(498, 4)));
+
+        let error = "498,x".parse::<Point<u32>>().unwrap_err();
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!(error.column(), Some(5));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Point::new(500, 0).to_string(), "(500, 0)");
+        assert_eq!(Point::new(1.5, -2.0).to_string(), "(1.5, -2)");
     }
 }
@@ -0,0 +1,265 @@
+//!
+//! # registry
+//! The table of every implemented day, so they can be found and run by year and day.
+//!
+//! A day registers itself with [`aoc_day!`](crate::aoc_day) in its own module: the puzzles are
+//! collected at startup, so a new day needs no other wiring than its `pub mod`.
+//!
+
+use std::any::Any;
+use std::sync::OnceLock;
+
+use crate::error::AocError;
+use crate::solution::{Answer, Part, Solution};
+use crate::visualize::Visualize;
+
+// Used by `aoc_day!`, so the days don't depend on inventory themselves
+#[doc(hidden)]
+pub use inventory;
+
+///
+/// # aoc_day
+///
+/// Declare the solution of a day and register it, so `aoc` and the tests can find it.
+///
+/// ## Arguments
+///
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+/// * `name` - The unit struct to declare, implementing `Solution`
+///
+/// ```ignore
+/// aoc_day!(2022, 10, Day10);
+///
+/// impl Solution for Day10 {
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! aoc_day {
+    ($year:literal, $day:literal, $name:ident) => {
+        pub struct $name;
+
+        $crate::registry::inventory::submit! {
+            $crate::registry::Puzzle::new::<$name>($year, $day)
+        }
+    };
+}
+
+///
+/// # Puzzle
+///
+/// A registered solution, with its `Solution` implementation erased so every day can be
+/// stored in the same table and dispatched at runtime.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Box<dyn Any>, AocError>,
+    part_one: fn(&dyn Any) -> Answer,
+    part_two: fn(&dyn Any) -> Answer,
+    visualize: fn(&dyn Any) -> Option<Box<dyn Visualize>>,
+}
+
+impl Puzzle {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Puzzle {
+            year,
+            day,
+            parse: parse::<S>,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            visualize: visualize::<S>,
+        }
+    }
+
+    ///
+    /// # parse
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The raw puzzle input
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Box<dyn Any>, AocError>` - The parsed input, to give back to `solve_parsed`
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        (self.parse)(input)
+    }
+
+    ///
+    /// # solve_parsed
+    ///
+    /// ## Arguments
+    ///
+    /// * `parsed` - An input returned by `parse`
+    /// * `part` - The part to solve
+    ///
+    /// ## Returns
+    ///
+    /// * `Answer` - The answer to the part
+    pub fn solve_parsed(&self, parsed: &dyn Any, part: Part) -> Answer {
+        match part {
+            Part::One => (self.part_one)(parsed),
+            Part::Two => (self.part_two)(parsed),
+        }
+    }
+
+    ///
+    /// # visualize
+    ///
+    /// ## Arguments
+    ///
+    /// * `parsed` - An input returned by `parse`
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Box<dyn Visualize>>` - The animation of the puzzle, if it has one
+    pub fn visualize(&self, parsed: &dyn Any) -> Option<Box<dyn Visualize>> {
+        (self.visualize)(parsed)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Box<dyn Any>, AocError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part_one<S: Solution>(parsed: &dyn Any) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_one(downcast::<S>(parsed))
+}
+
+fn part_two<S: Solution>(parsed: &dyn Any) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_two(downcast::<S>(parsed))
+}
+
+fn visualize<S: Solution>(parsed: &dyn Any) -> Option<Box<dyn Visualize>>
+where
+    S::Input: 'static,
+{
+    S::visualize(downcast::<S>(parsed))
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("the parsed input comes from another puzzle")
+}
+
+inventory::collect!(Puzzle);
+
+///
+/// # puzzles
+///
+/// ## Returns
+///
+/// * `&[&Puzzle]` - Every registered puzzle, sorted by year and day
+pub fn puzzles() -> &'static [&'static Puzzle] {
+    static PUZZLES: OnceLock<Vec<&'static Puzzle>> = OnceLock::new();
+
+    PUZZLES.get_or_init(|| {
+        let mut puzzles: Vec<&Puzzle> = inventory::iter::<Puzzle>.into_iter().collect();
+        puzzles.sort_by_key(|p| (p.year, p.day));
+        puzzles
+    })
+}
+
+///
+/// # find
+///
+/// ## Arguments
+///
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+///
+/// ## Returns
+///
+/// * `Option<&Puzzle>` - The registered puzzle, if it is implemented
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    puzzles()
+        .iter()
+        .copied()
+        .find(|p| p.year == year && p.day == day)
+}
+
+///
+/// # of_year
+///
+/// ## Arguments
+///
+/// * `year` - The year to list
+///
+/// ## Returns
+///
+/// * `impl Iterator<Item = &Puzzle>` - The implemented puzzles of the year
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    puzzles().iter().copied().filter(move |p| p.year == year)
+}
+
+///
+/// # years
+///
+/// ## Returns
+///
+/// * `Vec<u16>` - The years with at least one implemented puzzle
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = puzzles().iter().map(|p| p.year).collect();
+    years.dedup();
+    years
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_are_sorted_and_unique() {
+        assert!(puzzles()
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(2022, 16).is_some());
+        assert!(find(2022, 15).is_none());
+        assert_eq!(of_year(2015).count(), 6);
+        assert_eq!(years(), vec![2015, 2022, 2023, 2024]);
+    }
+
+    #[test]
+    fn test_solve_parsed() {
+        let puzzle = find(2015, 1).unwrap();
+        let parsed = puzzle.parse("()())").unwrap();
+
+        assert_eq!(
+            puzzle.solve_parsed(parsed.as_ref(), Part::One),
+            Answer::Number(-1)
+        );
+        assert_eq!(
+            puzzle.solve_parsed(parsed.as_ref(), Part::Two),
+            Answer::Number(5)
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//!
+//! # runner
+//! Run many puzzles at once on a pool of threads, for `aoc run --all`.
+//!
+//! Every day is solved in isolation: a panicking solution or an invalid input is reported in
+//! the summary without stopping the other days.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{AnswerStore, Verdict};
+use crate::bench::format_duration;
+use crate::error::AocError;
+use crate::input::InputLoader;
+use crate::registry::Puzzle;
+use crate::solution::{Answer, Part};
+
+// Variables  =========================================================================== Variables
+/// The longest answer shown in the summary, longer ones being cut.
+const MAX_ANSWER_WIDTH: usize = 24;
+
+///
+/// # PartRun
+///
+/// The answer of a part, and how long it took.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub duration: Duration,
+    pub verdict: Verdict,
+}
+
+///
+/// # Outcome
+///
+/// How the run of a puzzle ended.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    /// Both parts ran, their answers may still differ from the stored ones.
+    Solved {
+        parse: Duration,
+        parts: Vec<PartRun>,
+    },
+    /// The input couldn't be loaded, so the puzzle wasn't run.
+    Skipped(String),
+    /// The input was rejected by the parser.
+    InvalidInput(AocError),
+    /// The solution panicked, with the message of the panic.
+    Panicked(String),
+}
+
+///
+/// # PuzzleRun
+///
+/// The run of a puzzle.
+#[derive(Debug, Clone)]
+pub struct PuzzleRun {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: Outcome,
+    /// The time spent on the puzzle, parsing included.
+    pub duration: Duration,
+}
+
+impl PuzzleRun {
+    ///
+    /// # failed
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Whether the puzzle panicked, rejected its input or gave a wrong answer
+    pub fn failed(&self) -> bool {
+        match &self.outcome {
+            Outcome::Solved { parts, .. } => parts
+                .iter()
+                .any(|part| matches!(part.verdict, Verdict::Fail { .. })),
+            Outcome::Skipped(_) => false,
+            Outcome::InvalidInput(_) | Outcome::Panicked(_) => true,
+        }
+    }
+
+    ///
+    /// # status
+    ///
+    /// ## Returns
+    ///
+    /// * `String` - A short description of the outcome, for the summary
+    pub fn status(&self) -> String {
+        match &self.outcome {
+            Outcome::Solved { parts, .. } => {
+                let failed: Vec<String> = parts
+                    .iter()
+                    .filter_map(|part| match &part.verdict {
+                        Verdict::Fail { expected } => Some(format!(
+                            "FAIL part {}, expected {}",
+                            part.part,
+                            shorten(expected)
+                        )),
+                        _ => None,
+                    })
+                    .collect();
+
+                if failed.is_empty() {
+                    "ok".to_string()
+                } else {
+                    failed.join(", ")
+                }
+            }
+            Outcome::Skipped(reason) => format!("skipped, {}", reason),
+            Outcome::InvalidInput(error) => {
+                format!(
+                    "FAIL invalid input, {}",
+                    error.to_string().replace('\n', " ")
+                )
+            }
+            Outcome::Panicked(message) => format!("PANIC {}", message.replace('\n', " ")),
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # run_puzzle
+///
+/// Solve both parts of a puzzle, catching a panic of its solution.
+///
+/// ## Arguments
+///
+/// * `puzzle` - The puzzle to run
+/// * `input` - Its raw input
+/// * `store` - The known answers, to check the ones found
+///
+/// ## Returns
+///
+/// * `Outcome` - The answers and timings, or why there are none
+pub fn run_puzzle(puzzle: &Puzzle, input: &str, store: &AnswerStore) -> Outcome {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let start = Instant::now();
+        let parsed = match puzzle.parse(input) {
+            Ok(parsed) => parsed,
+            Err(error) => return Outcome::InvalidInput(error),
+        };
+        let parse = start.elapsed();
+
+        let parts = Part::ALL
+            .into_iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = puzzle.solve_parsed(parsed.as_ref(), part);
+                let duration = start.elapsed();
+
+                PartRun {
+                    part,
+                    verdict: store.check(puzzle.year, puzzle.day, part, &answer),
+                    answer,
+                    duration,
+                }
+            })
+            .collect();
+
+        Outcome::Solved { parse, parts }
+    }));
+
+    result.unwrap_or_else(|payload| Outcome::Panicked(panic_message(payload.as_ref())))
+}
+
+///
+/// # run_all
+///
+/// Run every puzzle on a pool of threads, each thread taking the next puzzle when it is done.
+///
+/// ## Arguments
+///
+/// * `puzzles` - The puzzles to run
+/// * `loader` - Where their inputs are loaded from
+/// * `store` - The known answers, to check the ones found
+/// * `threads` - The size of the pool, `0` for one thread per core
+///
+/// ## Returns
+///
+/// * `Vec<PuzzleRun>` - The runs, in the order of the puzzles
+pub fn run_all(
+    puzzles: &[&Puzzle],
+    loader: &InputLoader,
+    store: &AnswerStore,
+    threads: usize,
+) -> Vec<PuzzleRun> {
+    let threads = match threads {
+        0 => thread::available_parallelism().map_or(1, |threads| threads.get()),
+        threads => threads,
+    }
+    .min(puzzles.len())
+    .max(1);
+    let next = AtomicUsize::new(0);
+
+    let mut runs: Vec<(usize, PuzzleRun)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut runs = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&puzzle) = puzzles.get(index) else {
+                            break;
+                        };
+
+                        let start = Instant::now();
+                        let outcome = match loader.load(puzzle.year, puzzle.day) {
+                            Ok(input) => run_puzzle(puzzle, &input, store),
+                            Err(error) => Outcome::Skipped(error.to_string()),
+                        };
+
+                        runs.push((
+                            index,
+                            PuzzleRun {
+                                year: puzzle.year,
+                                day: puzzle.day,
+                                outcome,
+                                duration: start.elapsed(),
+                            },
+                        ));
+                    }
+                    runs
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("the panics are caught by run_puzzle"))
+            .collect()
+    });
+
+    runs.sort_by_key(|(index, _)| *index);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+///
+/// # summary_table
+///
+/// ## Arguments
+///
+/// * `runs` - The runs of the puzzles
+///
+/// ## Returns
+///
+/// * `String` - A table with one row per puzzle: its answers, its duration and its status
+pub fn summary_table(runs: &[PuzzleRun]) -> String {
+    let header = ["Year", "Day", "Part 1", "Part 2", "Time", "Status"].map(String::from);
+    let rows: Vec<[String; 6]> =
+        runs.iter()
+            .map(|run| {
+                let answer =
+                    |part: Part| match &run.outcome {
+                        Outcome::Solved { parts, .. } => parts
+                            .iter()
+                            .find(|p| p.part == part)
+                            .map_or("-".to_string(), |p| match p.answer {
+                                Answer::Unsolved => "-".to_string(),
+                                ref answer => shorten(&answer.to_string()),
+                            }),
+                        _ => "-".to_string(),
+                    };
+                let time = match run.outcome {
+                    Outcome::Skipped(_) => "-".to_string(),
+                    _ => format_duration(run.duration),
+                };
+
+                [
+                    run.year.to_string(),
+                    format!("{:02}", run.day),
+                    answer(Part::One),
+                    answer(Part::Two),
+                    time,
+                    run.status(),
+                ]
+            })
+            .collect();
+
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    let count = |f: fn(&PuzzleRun) -> bool| runs.iter().filter(|run| f(run)).count();
+    table.push_str(&format!(
+        "\n{} puzzles: {} ok, {} failed, {} skipped, {} in total\n",
+        runs.len(),
+        count(|run| !run.failed() && !matches!(run.outcome, Outcome::Skipped(_))),
+        count(PuzzleRun::failed),
+        count(|run| matches!(run.outcome, Outcome::Skipped(_))),
+        format_duration(runs.iter().map(|run| run.duration).sum())
+    ));
+
+    table
+}
+
+///
+/// # shorten
+///
+/// ## Arguments
+///
+/// * `answer` - A displayed answer
+///
+/// ## Returns
+///
+/// * `String` - The answer on a single line, cut if it is too long for the summary
+fn shorten(answer: &str) -> String {
+    let answer = if answer.contains('\n') {
+        format!("{:?}", answer)
+    } else {
+        answer.to_string()
+    };
+
+    if answer.chars().count() <= MAX_ANSWER_WIDTH {
+        answer
+    } else {
+        let cut: String = answer.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+        format!("{}…", cut)
+    }
+}
+
+///
+/// # panic_message
+///
+/// ## Arguments
+///
+/// * `payload` - The payload of a caught panic
+///
+/// ## Returns
+///
+/// * `String` - The message given to `panic!`, when it is a string
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+    use std::path::Path;
+
+    /// Doubles its input in the first part, and panics in the second.
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, AocError> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            (input * 2).into()
+        }
+
+        fn part_two(input: &Self::Input) -> Answer {
+            panic!("no second part for {}", input);
+        }
+    }
+
+    static DOUBLER: Puzzle = Puzzle::new::<Doubler>(2000, 1);
+
+    fn store() -> AnswerStore {
+        AnswerStore::parse("[year_2000.day_01]\npart_1 = 42", Path::new("answers.toml")).unwrap()
+    }
+
+    #[test]
+    fn test_run_puzzle_catches_panics() {
+        let outcome = run_puzzle(&DOUBLER, "21", &store());
+
+        match outcome {
+            Outcome::Panicked(message) => assert_eq!(message, "no second part for 21"),
+            outcome => panic!("expected a panic, got {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn test_run_puzzle_invalid_input() {
+        let outcome = run_puzzle(&DOUBLER, "twenty", &store());
+
+        assert!(matches!(outcome, Outcome::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let run = |day, outcome| PuzzleRun {
+            year: 2000,
+            day,
+            outcome,
+            duration: Duration::from_millis(2),
+        };
+        let part = |part, answer: Answer, verdict| PartRun {
+            part,
+            answer,
+            duration: Duration::from_millis(1),
+            verdict,
+        };
+        let runs = [
+            run(
+                1,
+                Outcome::Solved {
+                    parse: Duration::ZERO,
+                    parts: vec![
+                        part(Part::One, 42.into(), Verdict::Pass),
+                        part(
+                            Part::Two,
+                            "a\nb".into(),
+                            Verdict::Fail {
+                                expected: "ab".to_string(),
+                            },
+                        ),
+                    ],
+                },
+            ),
+            run(2, Outcome::Skipped("no input".to_string())),
+        ];
+
+        assert!(runs[0].failed());
+        assert!(!runs[1].failed());
+        assert_eq!(
+            summary_table(&runs),
+            "\
+Year  Day  Part 1  Part 2  Time     Status
+2000  01   42      \"a\\nb\"  2.00 ms  FAIL part 2, expected ab
+2000  02   -       -       -        skipped, no input
+
+2 puzzles: 0 ok, 1 failed, 1 skipped, 4.00 ms in total
+"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//!
+//! # scaffold
+//! Creation of the files of a new day, for `aoc new <year> <day>`.
+//!
+//! A new day gets its module from `templates/day.rs.template`, which registers itself with
+//! [`crate::aoc_day`], is declared in its year module, and gets an empty example with a test
+//! stub.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Variables  =========================================================================== Variables
+/// The module of a new day, with `{year}`, `{day}` (on two digits) and `{day_number}` to replace.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+/// The first year of Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+///
+/// # ScaffoldError
+///
+/// Error returned when a new day can't be created.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day isn't between 1 and 25, or the year is before the first Advent of Code.
+    InvalidDay { year: u16, day: u8 },
+    /// The directory isn't the root of the repository.
+    NotARepository(PathBuf),
+    /// The day already exists, nothing was written.
+    Exists { year: u16, day: u8, path: PathBuf },
+    /// A file of the repository couldn't be read or written.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay { year, day } => write!(
+                f,
+                "{} day {} doesn't exist, the days go from 1 to 25 since {}",
+                year, day, FIRST_YEAR
+            ),
+            ScaffoldError::NotARepository(root) => write!(
+                f,
+                "{} isn't the root of the repository (no src/registry.rs)",
+                root.display()
+            ),
+            ScaffoldError::Exists { year, day, path } => write!(
+                f,
+                "{} day {} already exists at {}",
+                year,
+                day,
+                path.display()
+            ),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "can't update {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # day_module
+///
+/// ## Arguments
+///
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+///
+/// ## Returns
+///
+/// * `String` - The module of the day, from the template
+pub fn day_module(year: u16, day: u8) -> String {
+    DAY_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", day))
+        .replace("{day_number}", &day.to_string())
+}
+
+///
+/// # year_module
+///
+/// ## Arguments
+///
+/// * `year` - The year of the puzzles
+///
+/// ## Returns
+///
+/// * `String` - The module of a year without any day
+pub fn year_module(year: u16) -> String {
+    format!(
+        "//!\n//! # year_{}\n//! Solutions of the Advent of Code challenge year {}\n//!\n\n",
+        year, year
+    )
+}
+
+///
+/// # insert_module
+///
+/// Declare a module among the `pub mod` lines sharing its prefix, keeping them sorted.
+///
+/// ## Arguments
+///
+/// * `content` - The content of the parent module
+/// * `name` - The name of the module, like `day_03` or `year_2025`
+///
+/// ## Returns
+///
+/// * `String` - The content with the declaration, unchanged if the module is already declared
+pub fn insert_module(content: &str, name: &str) -> String {
+    let declaration = format!("pub mod {};", name);
+    let prefix = format!("pub mod {}", &name[..name.find('_').map_or(0, |i| i + 1)]);
+
+    insert_sorted(content, &declaration, |line| {
+        line.starts_with(&prefix).then(|| line.to_string())
+    })
+}
+
+///
+/// # insert_sorted
+///
+/// ## Arguments
+///
+/// * `content` - The lines to insert into
+/// * `line` - The line to insert
+/// * `key` - The sort key of the lines it is sorted with, `None` for the other lines
+///
+/// ## Returns
+///
+/// * `String` - The content with the line before the first line of greater key, or after the
+///   last line having a key, or at the end if there is none
+fn insert_sorted<K: Ord>(content: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let new_key = key(line);
+
+    let keyed: Vec<(usize, K)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key(line)?)))
+        .collect();
+    if keyed.iter().any(|(_, key)| Some(key) == new_key.as_ref()) {
+        return content.to_string();
+    }
+
+    let index = match keyed.iter().find(|(_, key)| Some(key) > new_key.as_ref()) {
+        Some(&(index, _)) => index,
+        None => keyed.last().map_or(lines.len(), |&(index, _)| index + 1),
+    };
+    lines.insert(index, line);
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+///
+/// # scaffold
+///
+/// Create a new day in the repository: its module from the template, its declaration in the
+/// year module (created if needed), an empty example and its answers.
+/// Nothing is written if the day already exists.
+///
+/// ## Arguments
+///
+/// * `root` - The root of the repository
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+///
+/// ## Returns
+///
+/// * `Result<Vec<PathBuf>, ScaffoldError>` - The files created or updated
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if year < FIRST_YEAR || !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay { year, day });
+    }
+
+    let registry_path = root.join("src").join("registry.rs");
+    if !registry_path.is_file() {
+        return Err(ScaffoldError::NotARepository(root.to_path_buf()));
+    }
+
+    let year_dir = root.join("src").join(format!("year_{}", year));
+    let day_path = year_dir.join(format!("day_{:02}.rs", day));
+    let exists = |path: PathBuf| ScaffoldError::Exists { year, day, path };
+    if day_path.exists() {
+        return Err(exists(day_path));
+    }
+
+    // Every file is prepared before writing any of them
+    let mut files = vec![(day_path, day_module(year, day))];
+
+    let year_path = year_dir.join("mod.rs");
+    let year_content = if year_path.exists() {
+        read(&year_path)?
+    } else {
+        let lib_path = root.join("src").join("lib.rs");
+        let lib = insert_module(&read(&lib_path)?, &format!("year_{}", year));
+        files.push((lib_path, lib));
+        year_module(year)
+    };
+    files.push((
+        year_path,
+        insert_module(&year_content, &format!("day_{:02}", day)),
+    ));
+
+    let examples_dir = root
+        .join("data")
+        .join(format!("year_{}", year))
+        .join("examples");
+    let example_path = examples_dir.join(format!("day_{:02}_1.txt", day));
+    if !example_path.exists() {
+        files.push((example_path, String::new()));
+    }
+
+    let answers_path = examples_dir.join("answers.toml");
+    let mut answers = if answers_path.exists() {
+        read(&answers_path)?
+    } else {
+        String::new()
+    };
+    let section = format!("[day_{:02}_1]", day);
+    if !answers.lines().any(|line| line.trim() == section) {
+        if !answers.is_empty() {
+            answers.push('\n');
+        }
+        answers.push_str(&format!("{}\n# part_1 =\n# part_2 =\n", section));
+        files.push((answers_path, answers));
+    }
+
+    for (path, content) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|source| ScaffoldError::Io {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(path, content).map_err(|source| ScaffoldError::Io {
+            path: path.clone(),
+            source,
+        })?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+///
+/// # read
+///
+/// ## Arguments
+///
+/// * `path` - A file of the repository
+///
+/// ## Returns
+///
+/// * `Result<String, ScaffoldError>` - Its content
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_module() {
+        let module = day_module(2024, 3);
+
+        assert!(
+            module.contains("//! Code for the day 03 of the Advent of Code challenge year 2024")
+        );
+        assert!(module.contains("aoc_day!(2024, 3, Day03);"));
+        assert!(module.contains("impl Solution for Day03 {"));
+        assert!(module.contains("data/year_2024/examples/day_03_1.txt"));
+        assert!(!module.contains("{day") && !module.contains("{year}"));
+    }
+
+    #[test]
+    fn test_insert_module() {
+        let content = "//! # year_2024\n\npub mod day_01;\npub mod day_05;\n";
+
+        assert_eq!(
+            insert_module(content, "day_03"),
+            "//! # year_2024\n\npub mod day_01;\npub mod day_03;\npub mod day_05;\n"
+        );
+        assert_eq!(
+            insert_module(content, "day_07"),
+            "//! # year_2024\n\npub mod day_01;\npub mod day_05;\npub mod day_07;\n"
+        );
+        assert_eq!(insert_module(content, "day_05"), content);
+        assert_eq!(
+            insert_module(&year_module(2025), "day_01"),
+            year_module(2025) + "pub mod day_01;\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/registry.rs"), "").unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod year_2015;\npub mod year_2024;\n",
+        )
+        .unwrap();
+
+        let created = scaffold(&root, 2025, 2).unwrap();
+        let again = scaffold(&root, 2025, 2);
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        let year = fs::read_to_string(root.join("src/year_2025/mod.rs")).unwrap();
+        let answers =
+            fs::read_to_string(root.join("data/year_2025/examples/answers.toml")).unwrap();
+        let example = root.join("data/year_2025/examples/day_02_1.txt").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(created.len(), 5);
+        assert!(matches!(again, Err(ScaffoldError::Exists { .. })));
+        assert_eq!(
+            lib,
+            "pub mod year_2015;\npub mod year_2024;\npub mod year_2025;\n"
+        );
+        assert!(year.ends_with("pub mod day_02;\n"));
+        assert_eq!(answers, "[day_02_1]\n# part_1 =\n# part_2 =\n");
+        assert!(example);
+
+        assert!(matches!(
+            scaffold(&root, 2024, 26),
+            Err(ScaffoldError::InvalidDay { .. })
+        ));
+    }
+}
@@ -0,0 +1,375 @@
+//!
+//! # search
+//! Shortest path searches over any graph, described by a function giving the successors of a
+//! node.
+//!
+//! * [`bfs`] and [`bfs_multi`] - Every move costs 1
+//! * [`dijkstra`] - The moves have a cost
+//! * [`astar`] - The moves have a cost, and the remaining cost can be estimated
+//!
+
+// Imports  ==============================================================================  Imports
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Variables  =========================================================================== Variables
+///
+/// # Found
+///
+/// A goal reached by a search, with the cost to reach it.
+/// The path is only rebuilt when asked for.
+#[derive(Debug, Clone)]
+pub struct Found<N, C> {
+    pub goal: N,
+    pub cost: C,
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    goal_index: usize,
+}
+
+impl<N: Clone, C> Found<N, C> {
+    ///
+    /// # path
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<N>` - The nodes from the start to the goal, both included
+    pub fn path(&self) -> Vec<N> {
+        let mut path: Vec<N> =
+            std::iter::successors(Some(self.goal_index), |&index| self.parents[index])
+                .map(|index| self.nodes[index].clone())
+                .collect();
+        path.reverse();
+        path
+    }
+}
+
+///
+/// # Visited
+///
+/// The nodes seen by a search, each one numbered in the order it was found.
+struct Visited<N, C> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+    indexes: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new() -> Self {
+        Visited {
+            nodes: Vec::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+
+    ///
+    /// # insert
+    ///
+    /// ## Arguments
+    ///
+    /// * `node` - A node reached by the search
+    /// * `parent` - The index of the node it was reached from, `None` for a start
+    /// * `cost` - The cost to reach it
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<usize>` - The index of the node, `None` if it was already reached more cheaply
+    fn insert(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.indexes.get(&node) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.parents[index] = parent;
+                self.costs[index] = cost;
+                Some(index)
+            }
+            None => {
+                let index = self.nodes.len();
+                self.indexes.insert(node.clone(), index);
+                self.nodes.push(node);
+                self.parents.push(parent);
+                self.costs.push(cost);
+                Some(index)
+            }
+        }
+    }
+
+    fn found(self, goal_index: usize) -> Found<N, C> {
+        Found {
+            goal: self.nodes[goal_index].clone(),
+            cost: self.costs[goal_index],
+            nodes: self.nodes,
+            parents: self.parents,
+            goal_index,
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # bfs
+///
+/// Breadth-first search, for the graphs where every move costs 1.
+///
+/// ## Arguments
+///
+/// * `start` - The node to start from
+/// * `successors` - The nodes reachable in one move from a node
+/// * `is_goal` - Whether a node is the one looked for
+///
+/// ## Returns
+///
+/// * `Option<Found<N, usize>>` - The closest goal and its number of moves, `None` if no goal
+///   can be reached
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], successors, is_goal)
+}
+
+///
+/// # bfs_multi
+///
+/// Breadth-first search from several nodes at once, finding the goal closest to any of them.
+///
+/// ## Arguments
+///
+/// * `starts` - The nodes to start from
+/// * `successors` - The nodes reachable in one move from a node
+/// * `is_goal` - Whether a node is the one looked for
+///
+/// ## Returns
+///
+/// * `Option<Found<N, usize>>` - The closest goal and its number of moves, `None` if no goal
+///   can be reached
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(index) = visited.insert(start, None, 0) {
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.found(index));
+        }
+
+        let cost = visited.costs[index] + 1;
+        for next in successors(&visited.nodes[index]) {
+            // The first time a node is reached is always with its lowest cost
+            if let Some(next_index) = visited.insert(next, Some(index), cost) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+///
+/// # dijkstra
+///
+/// Dijkstra's algorithm, for the graphs where the moves have a non-negative cost.
+///
+/// ## Arguments
+///
+/// * `start` - The node to start from
+/// * `successors` - The nodes reachable in one move from a node, with the cost of the move
+/// * `is_goal` - Whether a node is the one looked for
+///
+/// ## Returns
+///
+/// * `Option<Found<N, C>>` - The cheapest goal and its cost, `None` if no goal can be reached
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+///
+/// # astar
+///
+/// A* search: Dijkstra's algorithm exploring first the nodes that look closer to a goal.
+/// The heuristic must never overestimate the remaining cost, or the path found may not be
+/// the cheapest.
+///
+/// ## Arguments
+///
+/// * `start` - The node to start from
+/// * `successors` - The nodes reachable in one move from a node, with the cost of the move
+/// * `heuristic` - An estimation of the cost from a node to the closest goal
+/// * `is_goal` - Whether a node is the one looked for
+///
+/// ## Returns
+///
+/// * `Option<Found<N, C>>` - The cheapest goal and its cost, `None` if no goal can be reached
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+
+    let index = visited.insert(start, None, C::default())?;
+    heap.push(Reverse((
+        heuristic(&visited.nodes[index]),
+        C::default(),
+        index,
+    )));
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // Skip the entries of the nodes reached more cheaply since they were pushed
+        if cost > visited.costs[index] {
+            continue;
+        }
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.found(index));
+        }
+
+        for (next, move_cost) in successors(&visited.nodes[index]) {
+            let next_cost = cost + move_cost;
+            if let Some(next_index) = visited.insert(next, Some(index), next_cost) {
+                let estimate = next_cost + heuristic(&visited.nodes[next_index]);
+                heap.push(Reverse((estimate, next_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    const MAZE: &str = "S..#\n.#.#\n.#..\n...E";
+
+    fn open_neighbors(grid: &Grid<char>, point: Point<usize>) -> Vec<Point<usize>> {
+        grid.neighbors4(point)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let found = bfs(
+            Point::new(0, 0),
+            |&point| open_neighbors(&grid, point),
+            |&point| point == end,
+        )
+        .unwrap();
+
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path().len(), 7);
+        assert_eq!(found.path()[0], Point::new(0, 0));
+        assert_eq!(found.path()[6], end);
+
+        assert!(bfs(
+            Point::new(0, 0),
+            |&point| open_neighbors(&grid, point),
+            |&point| point == Point::new(3, 0),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_bfs_multi() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let found = bfs_multi(
+            [Point::new(0, 0), Point::new(2, 1)],
+            |&point| open_neighbors(&grid, point),
+            |&point| point == end,
+        )
+        .unwrap();
+
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path()[0], Point::new(2, 1));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // The direct edge is more expensive than the detour
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![('b', 1)]),
+        ]);
+
+        let found = dijkstra(
+            'a',
+            |node| edges.get(node).cloned().unwrap_or_default(),
+            |&node| node == 'b',
+        )
+        .unwrap();
+
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path(), vec!['a', 'c', 'd', 'b']);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let found = astar(
+            Point::new(0, 0),
+            |&point| {
+                open_neighbors(&grid, point)
+                    .into_iter()
+                    .map(|next| (next, 1))
+            },
+            |point| point.manhattan_distance(&end),
+            |&point| point == end,
+        )
+        .unwrap();
+
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path().last(), Some(&end));
+    }
+}
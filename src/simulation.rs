@@ -0,0 +1,355 @@
+//!
+//! # simulation
+//! Step-wise simulations, and the runners shared by the days simulating something: a number of
+//! steps, until the simulation is over or stable, or far into the future by finding a cycle.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Variables  =========================================================================== Variables
+///
+/// # Simulation
+///
+/// Something that evolves one step at a time.
+pub trait Simulation {
+    ///
+    /// # step
+    ///
+    /// Advance the simulation by one step. Never called once the simulation is done.
+    fn step(&mut self);
+
+    ///
+    /// # is_done
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Whether the simulation is over. Defaults to `false`, a simulation running
+    ///   forever.
+    fn is_done(&self) -> bool {
+        false
+    }
+}
+
+///
+/// # Cyclic
+///
+/// A simulation whose state can be compared with its previous states, to find when it repeats.
+pub trait Cyclic: Simulation {
+    /// What identifies a state: two equal states must evolve the same way.
+    type State: Hash + Eq;
+
+    ///
+    /// # state
+    ///
+    /// ## Returns
+    ///
+    /// * `Self::State` - The current state
+    fn state(&self) -> Self::State;
+}
+
+///
+/// # Cycle
+///
+/// The states repeating forever: the state after `start + length` steps is the state after
+/// `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first repeated state.
+    pub start: usize,
+    /// The number of steps between two occurrences of a state.
+    pub length: usize,
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # run
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to advance
+/// * `steps` - The number of steps to run
+///
+/// ## Returns
+///
+/// * `usize` - The number of steps run, fewer than `steps` if the simulation ended before
+pub fn run<S: Simulation + ?Sized>(simulation: &mut S, steps: usize) -> usize {
+    for step in 0..steps {
+        if simulation.is_done() {
+            return step;
+        }
+        simulation.step();
+    }
+
+    steps
+}
+
+///
+/// # run_until_done
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to advance, which must end
+///
+/// ## Returns
+///
+/// * `usize` - The number of steps run
+pub fn run_until_done<S: Simulation + ?Sized>(simulation: &mut S) -> usize {
+    let mut steps = 0;
+    while !simulation.is_done() {
+        simulation.step();
+        steps += 1;
+    }
+
+    steps
+}
+
+///
+/// # run_until_stable
+///
+/// Run until a step leaves the state unchanged, or the simulation ends.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to advance
+///
+/// ## Returns
+///
+/// * `usize` - The number of steps run, including the last one which changed nothing
+pub fn run_until_stable<S: Cyclic + ?Sized>(simulation: &mut S) -> usize {
+    let mut steps = 0;
+    let mut state = simulation.state();
+
+    while !simulation.is_done() {
+        simulation.step();
+        steps += 1;
+
+        let next = simulation.state();
+        if next == state {
+            break;
+        }
+        state = next;
+    }
+
+    steps
+}
+
+///
+/// # find_cycle
+///
+/// Run until a state repeats, remembering every state seen.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to advance
+/// * `limit` - The most steps to run before giving up
+///
+/// ## Returns
+///
+/// * `Option<Cycle>` - Where the states start repeating, `None` if the simulation ended or
+///   reached the limit first
+pub fn find_cycle<S: Cyclic + ?Sized>(simulation: &mut S, limit: usize) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+
+    for step in 0..=limit {
+        match seen.entry(simulation.state()) {
+            Entry::Occupied(entry) => {
+                return Some(Cycle {
+                    start: *entry.get(),
+                    length: step - entry.get(),
+                })
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+            }
+        }
+
+        if simulation.is_done() {
+            return None;
+        }
+        simulation.step();
+    }
+
+    None
+}
+
+///
+/// # extrapolate
+///
+/// Measure the simulation after a number of steps too large to run them all, like `10^12`.
+/// The simulation is run until its states repeat; the measure is then assumed to grow by the
+/// same amount on every cycle, like the height of a tower growing by the same blocks.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to advance
+/// * `target` - The number of steps after which to measure
+/// * `limit` - The most steps to run before giving up
+/// * `measure` - The value to extrapolate, measured after every step
+///
+/// ## Returns
+///
+/// * `Option<i64>` - The measure after `target` steps, `None` if the simulation ended or reached
+///   the limit before reaching `target` or repeating a state
+pub fn extrapolate<S: Cyclic + ?Sized>(
+    simulation: &mut S,
+    target: u64,
+    limit: usize,
+    mut measure: impl FnMut(&S) -> i64,
+) -> Option<i64> {
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+
+    for step in 0..=limit {
+        values.push(measure(simulation));
+        if step as u64 == target {
+            return values.last().copied();
+        }
+
+        match seen.entry(simulation.state()) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let length = (step - start) as u64;
+                let remaining = target - start as u64;
+                let offset = (remaining % length) as usize;
+                let gain = values[step] - values[start];
+
+                return Some(values[start + offset] + (remaining / length) as i64 * gain);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+            }
+        }
+
+        if simulation.is_done() {
+            return None;
+        }
+        simulation.step();
+    }
+
+    None
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A counter modulo `modulus` starting at `start`, with the total of the values it took.
+    struct Counter {
+        value: u32,
+        modulus: u32,
+        total: i64,
+        end: Option<u32>,
+    }
+
+    impl Counter {
+        fn new(start: u32, modulus: u32) -> Self {
+            Counter {
+                value: start,
+                modulus,
+                total: 0,
+                end: None,
+            }
+        }
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) {
+            self.total += self.value as i64;
+            // A start at or above the modulus is only seen once, before the counter wraps
+            self.value = (self.value + 1) % self.modulus;
+        }
+
+        fn is_done(&self) -> bool {
+            self.end == Some(self.value)
+        }
+    }
+
+    impl Cyclic for Counter {
+        type State = u32;
+
+        fn state(&self) -> u32 {
+            self.value
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut counter = Counter::new(0, 10);
+        assert_eq!(run(&mut counter, 4), 4);
+        assert_eq!(counter.value, 4);
+
+        counter.end = Some(7);
+        assert_eq!(run(&mut counter, 10), 3);
+        assert_eq!(run_until_done(&mut counter), 0);
+
+        let mut counter = Counter::new(2, 10);
+        counter.end = Some(0);
+        assert_eq!(run_until_done(&mut counter), 8);
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let mut counter = Counter::new(0, 1);
+        assert_eq!(run_until_stable(&mut counter), 1);
+
+        let mut counter = Counter::new(3, 5);
+        counter.end = Some(1);
+        assert_eq!(run_until_stable(&mut counter), 3);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 12, 13, ... 19, then 0, 1, ... 11, 12 repeats every 20 steps
+        let mut counter = Counter::new(12, 20);
+        assert_eq!(
+            find_cycle(&mut counter, 100),
+            Some(Cycle {
+                start: 0,
+                length: 20
+            })
+        );
+        assert_eq!(find_cycle(&mut Counter::new(12, 20), 10), None);
+
+        // 25 is above the modulus, it is never seen again
+        let mut counter = Counter::new(25, 20);
+        assert_eq!(
+            find_cycle(&mut counter, 100),
+            Some(Cycle {
+                start: 1,
+                length: 20
+            })
+        );
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let total = |steps: u64| {
+            let mut counter = Counter::new(25, 20);
+            run(&mut counter, steps as usize);
+            counter.total
+        };
+        let measure = |counter: &Counter| counter.total;
+
+        for steps in [0, 1, 7, 21, 22, 45, 100] {
+            let mut counter = Counter::new(25, 20);
+            assert_eq!(
+                extrapolate(&mut counter, steps, 1000, measure),
+                Some(total(steps)),
+                "{} steps",
+                steps
+            );
+        }
+
+        // The first step adds 25, then every cycle of 20 steps adds 0 + 1 + ... + 19 = 190:
+        // 1 + 10^12 steps are the first step and 5 * 10^10 whole cycles
+        let mut counter = Counter::new(25, 20);
+        assert_eq!(
+            extrapolate(&mut counter, 1_000_000_000_001, 1000, measure),
+            Some(25 + 190 * 50_000_000_000)
+        );
+    }
+}
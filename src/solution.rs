@@ -0,0 +1,216 @@
+//!
+//! # solution
+//! The interface shared by every day, and the answers it produces.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::visualize::Visualize;
+
+///
+/// # Answer
+///
+/// The answer to one part of a puzzle.
+/// Most answers are numbers, but some puzzles expect a string (the crate tops of 2022 day 05,
+/// the letters drawn on the CRT of 2022 day 10, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A numeric answer, wide enough to hold every integer type used by the solutions.
+    Number(i128),
+    /// A textual answer.
+    Text(String),
+    /// No answer was produced, either because the part is not implemented yet
+    /// or because the solution could not find one.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// Every integer type the solutions return can be turned into an `Answer` losslessly.
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+///
+/// # Part
+///
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    ///
+    /// # number
+    ///
+    /// ## Returns
+    ///
+    /// * `u8` - 1 for the first part, 2 for the second one
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
+}
+
+///
+/// # Solution
+///
+/// A puzzle solution.
+/// The input is parsed once and both parts are computed from the parsed value, so the
+/// answers can be tested, compared or timed without going through stdout.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    ///
+    /// # parse
+    ///
+    /// Parse the raw puzzle input.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The raw puzzle input
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<Self::Input, AocError>` - The parsed input, or where it is malformed
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    ///
+    /// # part_one
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The parsed input
+    ///
+    /// ## Returns
+    ///
+    /// * `Answer` - The answer to the first part of the puzzle
+    fn part_one(input: &Self::Input) -> Answer;
+
+    ///
+    /// # part_two
+    ///
+    /// Unsolved by default, until the second part is implemented.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The parsed input
+    ///
+    /// ## Returns
+    ///
+    /// * `Answer` - The answer to the second part of the puzzle
+    fn part_two(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    ///
+    /// # visualize
+    ///
+    /// None by default, for the days without an animation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The parsed input
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Box<dyn Visualize>>` - The simulation to animate with `aoc run --visualize`
+    fn visualize(_input: &Self::Input) -> Option<Box<dyn Visualize>> {
+        None
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(42u8), Answer::Number(42));
+        assert_eq!(Answer::from(-7i64), Answer::Number(-7));
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::from(Some(3usize)), Answer::Number(3));
+        assert_eq!(Answer::from(None::<usize>), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::Number(-12).to_string(), "-12");
+        assert_eq!(Answer::from("MCD").to_string(), "MCD");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
@@ -0,0 +1,748 @@
+//!
+//! # submit
+//! Submission of the answers to the website, used by `aoc submit`.
+//!
+//! Every answer sent is recorded in `<data dir>/guesses.toml` with the feedback of the website.
+//! The history is checked before sending anything: an answer already known to be wrong, or out
+//! of the bounds given by the "too high" and "too low" feedbacks, is refused without a request,
+//! and so is any answer to a part already solved.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::client::{Client, ClientError};
+use crate::solution::{Answer, Part};
+
+// Variables  =========================================================================== Variables
+/// Name of the guess history, inside the data directory.
+pub const GUESSES_FILE: &str = "guesses.toml";
+
+/// The time left to wait before the next submission, in minutes and seconds.
+static WAIT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"You have (?:(\d+)m)? ?(?:(\d+)s)? left to wait").unwrap());
+/// The `<article>` holding the answer of the website.
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+/// An HTML tag.
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+///
+/// # Feedback
+///
+/// What the website answered to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Feedback {
+    /// The answer is right, the part is solved.
+    Correct,
+    /// The answer is wrong, and too high.
+    TooHigh,
+    /// The answer is wrong, and too low.
+    TooLow,
+    /// The answer is wrong, without a hint.
+    Wrong,
+    /// An answer was sent too recently, nothing was checked.
+    Wait { seconds: u64 },
+    /// The part was already solved, nothing was checked.
+    AlreadySolved,
+    /// A page that isn't understood, with its text.
+    Unknown(String),
+}
+
+impl Feedback {
+    ///
+    /// # key
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<&str>` - The name of the feedback in the guess history, `None` for the
+    ///   feedbacks that say nothing about the answer
+    pub fn key(&self) -> Option<&'static str> {
+        match self {
+            Feedback::Correct => Some("correct"),
+            Feedback::TooHigh => Some("too_high"),
+            Feedback::TooLow => Some("too_low"),
+            Feedback::Wrong => Some("wrong"),
+            Feedback::Wait { .. } | Feedback::AlreadySolved | Feedback::Unknown(_) => None,
+        }
+    }
+
+    ///
+    /// # from_key
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` - The name of a feedback in the guess history
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Feedback>` - The feedback, if the name is known
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Feedback::Correct),
+            "too_high" => Some(Feedback::TooHigh),
+            "too_low" => Some(Feedback::TooLow),
+            "wrong" => Some(Feedback::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "wrong, too high"),
+            Feedback::TooLow => write!(f, "wrong, too low"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::Wait { seconds } => {
+                write!(f, "answered too recently, wait {}s", seconds)
+            }
+            Feedback::AlreadySolved => write!(f, "already solved"),
+            Feedback::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+///
+/// # Refusal
+///
+/// Why an answer wasn't sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The solution didn't find an answer.
+    Unsolved,
+    /// The part was already solved with this answer.
+    AlreadySolved { answer: String },
+    /// The same answer was already sent.
+    AlreadyGuessed { feedback: Feedback },
+    /// The answer isn't below an answer known to be too high.
+    AboveTooHigh { bound: String },
+    /// The answer isn't above an answer known to be too low.
+    BelowTooLow { bound: String },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Unsolved => write!(f, "there is no answer to submit"),
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part is already solved, the answer is {}", answer)
+            }
+            Refusal::AlreadyGuessed { feedback } => {
+                write!(f, "this answer was already submitted, it is {}", feedback)
+            }
+            Refusal::AboveTooHigh { bound } => {
+                write!(f, "{} was already too high", bound)
+            }
+            Refusal::BelowTooLow { bound } => {
+                write!(f, "{} was already too low", bound)
+            }
+        }
+    }
+}
+
+///
+/// # SubmitError
+///
+/// Error returned when an answer can't be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The guess history says the answer is wrong, it wasn't sent.
+    Refused(Refusal),
+    /// The website couldn't be reached, or answered with an error.
+    Client(ClientError),
+    /// The guess history couldn't be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// The guess history isn't valid TOML.
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The guess history is valid TOML, but an entry isn't a guess.
+    Invalid { path: PathBuf, index: usize },
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+            SubmitError::Client(error) => write!(f, "{}", error),
+            SubmitError::Io { path, source } => {
+                write!(f, "can't access {}: {}", path.display(), source)
+            }
+            SubmitError::Toml { path, source } => {
+                write!(f, "invalid guess history {}: {}", path.display(), source)
+            }
+            SubmitError::Invalid { path, index } => write!(
+                f,
+                "invalid guess history {}: guess #{} should have a year, day, part, answer and feedback",
+                path.display(),
+                index + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SubmitError::Client(error) => Some(error),
+            SubmitError::Io { source, .. } => Some(source),
+            SubmitError::Toml { source, .. } => Some(source),
+            SubmitError::Refused(_) | SubmitError::Invalid { .. } => None,
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(error: ClientError) -> Self {
+        SubmitError::Client(error)
+    }
+}
+
+///
+/// # Guess
+///
+/// An answer sent to the website, and its feedback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+///
+/// # GuessHistory
+///
+/// Every answer sent, stored as TOML in the data directory:
+///
+/// ```toml
+/// [[guess]]
+/// year = 2022
+/// day = 1
+/// part = 1
+/// answer = "72000"
+/// feedback = "too_high"
+/// ```
+#[derive(Debug, Clone)]
+pub struct GuessHistory {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    ///
+    /// # path
+    ///
+    /// ## Arguments
+    ///
+    /// * `data_dir` - The data directory
+    ///
+    /// ## Returns
+    ///
+    /// * `PathBuf` - Where the guess history is kept
+    pub fn path(data_dir: &Path) -> PathBuf {
+        data_dir.join(GUESSES_FILE)
+    }
+
+    ///
+    /// # load
+    ///
+    /// Load the guess history. A missing file is an empty history.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The guess history file
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<GuessHistory, SubmitError>` - The recorded guesses
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match fs::read_to_string(path) {
+            Ok(content) => GuessHistory::parse(&content, path),
+            Err(source) if source.kind() == io::ErrorKind::NotFound => {
+                Ok(GuessHistory::empty(path))
+            }
+            Err(source) => Err(SubmitError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    ///
+    /// # parse
+    ///
+    /// ## Arguments
+    ///
+    /// * `content` - The content of the guess history
+    /// * `path` - The guess history file, where it will be saved
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<GuessHistory, SubmitError>` - The recorded guesses
+    pub fn parse(content: &str, path: &Path) -> Result<Self, SubmitError> {
+        let table: toml::Table = content.parse().map_err(|source| SubmitError::Toml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let entries = match table.get("guess") {
+            Some(entries) => entries.as_array().ok_or(SubmitError::Invalid {
+                path: path.to_path_buf(),
+                index: 0,
+            })?,
+            None => return Ok(GuessHistory::empty(path)),
+        };
+
+        let guesses = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                parse_guess(entry).ok_or(SubmitError::Invalid {
+                    path: path.to_path_buf(),
+                    index,
+                })
+            })
+            .collect::<Result<Vec<Guess>, SubmitError>>()?;
+
+        Ok(GuessHistory {
+            path: path.to_path_buf(),
+            guesses,
+        })
+    }
+
+    ///
+    /// # empty
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The guess history file, where it will be saved
+    ///
+    /// ## Returns
+    ///
+    /// * `GuessHistory` - A history without any guess
+    pub fn empty(path: &Path) -> Self {
+        GuessHistory {
+            path: path.to_path_buf(),
+            guesses: Vec::new(),
+        }
+    }
+
+    ///
+    /// # save
+    ///
+    /// Write the guess history to its file, creating the data directory if needed.
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<(), SubmitError>` - An error if the file can't be written
+    pub fn save(&self) -> Result<(), SubmitError> {
+        let io_error = |source| SubmitError::Io {
+            path: self.path.clone(),
+            source,
+        };
+
+        let content: String = self
+            .guesses
+            .iter()
+            .map(|guess| {
+                format!(
+                    "[[guess]]\nyear = {}\nday = {}\npart = {}\nanswer = {}\nfeedback = \"{}\"\n\n",
+                    guess.year,
+                    guess.day,
+                    guess.part,
+                    toml::Value::String(guess.answer.clone()),
+                    guess.feedback.key().unwrap_or("wrong")
+                )
+            })
+            .collect();
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(&self.path, content.trim_end().to_string() + "\n").map_err(io_error)
+    }
+
+    ///
+    /// # guesses
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `part` - The part of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `impl Iterator<Item = &Guess>` - The guesses sent for this part, oldest first
+    pub fn guesses(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.year == year && guess.day == day && guess.part == part)
+    }
+
+    ///
+    /// # check
+    ///
+    /// Decide whether an answer is worth sending, from the previous guesses of the part.
+    ///
+    /// ## Arguments
+    ///
+    /// * `year` - The year of the puzzle
+    /// * `day` - The day of the puzzle
+    /// * `part` - The part of the puzzle
+    /// * `answer` - The answer to send
+    ///
+    /// ## Returns
+    ///
+    /// * `Result<(), Refusal>` - Why the answer shouldn't be sent, if it shouldn't
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<(), Refusal> {
+        let text = match answer {
+            Answer::Unsolved => return Err(Refusal::Unsolved),
+            answer => answer.to_string(),
+        };
+
+        if let Some(solved) = self
+            .guesses(year, day, part)
+            .find(|guess| guess.feedback == Feedback::Correct)
+        {
+            return Err(Refusal::AlreadySolved {
+                answer: solved.answer.clone(),
+            });
+        }
+        if let Some(guess) = self
+            .guesses(year, day, part)
+            .find(|guess| guess.answer == text)
+        {
+            return Err(Refusal::AlreadyGuessed {
+                feedback: guess.feedback.clone(),
+            });
+        }
+
+        // The bounds only make sense for numbers
+        let Answer::Number(number) = *answer else {
+            return Ok(());
+        };
+        let bound = |feedback: Feedback| {
+            self.guesses(year, day, part)
+                .filter(move |guess| guess.feedback == feedback)
+                .filter_map(|guess| guess.answer.parse::<i128>().ok())
+        };
+
+        if let Some(lowest) = bound(Feedback::TooHigh).min().filter(|&b| number >= b) {
+            return Err(Refusal::AboveTooHigh {
+                bound: lowest.to_string(),
+            });
+        }
+        if let Some(highest) = bound(Feedback::TooLow).max().filter(|&b| number <= b) {
+            return Err(Refusal::BelowTooLow {
+                bound: highest.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// # record
+    ///
+    /// Add a guess to the history, if its feedback says something about the answer.
+    ///
+    /// ## Arguments
+    ///
+    /// * `guess` - The answer sent and its feedback
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - Whether the guess was recorded
+    pub fn record(&mut self, guess: Guess) -> bool {
+        if guess.feedback.key().is_none() {
+            return false;
+        }
+
+        self.guesses.push(guess);
+        true
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # parse_guess
+///
+/// ## Arguments
+///
+/// * `entry` - A `[[guess]]` table of the guess history
+///
+/// ## Returns
+///
+/// * `Option<Guess>` - The guess, if every field is valid
+fn parse_guess(entry: &toml::Value) -> Option<Guess> {
+    let integer = |key: &str| entry.get(key)?.as_integer();
+    let string = |key: &str| entry.get(key)?.as_str();
+
+    Some(Guess {
+        year: u16::try_from(integer("year")?).ok()?,
+        day: u8::try_from(integer("day")?).ok()?,
+        part: integer("part")?.to_string().parse().ok()?,
+        answer: string("answer")?.to_string(),
+        feedback: Feedback::from_key(string("feedback")?)?,
+    })
+}
+
+///
+/// # parse_feedback
+///
+/// Read the page answering a submission.
+///
+/// ## Arguments
+///
+/// * `page` - The HTML of the page
+///
+/// ## Returns
+///
+/// * `Feedback` - What the page says about the answer
+pub fn parse_feedback(page: &str) -> Feedback {
+    let text = article_text(page);
+
+    if text.contains("That's the right answer") {
+        Feedback::Correct
+    } else if text.contains("your answer is too high") {
+        Feedback::TooHigh
+    } else if text.contains("your answer is too low") {
+        Feedback::TooLow
+    } else if text.contains("That's not the right answer") {
+        Feedback::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        let seconds = WAIT.captures(&text).map_or(0, |captures| {
+            let number = |index| {
+                captures
+                    .get(index)
+                    .map_or(0, |m| m.as_str().parse::<u64>().unwrap_or(0))
+            };
+            number(1) * 60 + number(2)
+        });
+        Feedback::Wait { seconds }
+    } else if text.contains("Did you already complete it?") {
+        Feedback::AlreadySolved
+    } else {
+        Feedback::Unknown(text)
+    }
+}
+
+///
+/// # article_text
+///
+/// ## Arguments
+///
+/// * `page` - The HTML of a page of the website
+///
+/// ## Returns
+///
+/// * `String` - The text of its `<article>`, or of the whole page, without the tags and with
+///   the spaces collapsed
+fn article_text(page: &str) -> String {
+    let html = ARTICLE
+        .captures(page)
+        .and_then(|captures| captures.get(1))
+        .map_or(page, |m| m.as_str());
+
+    TAG.replace_all(html, " ")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+///
+/// # submit
+///
+/// Send an answer to the website, unless the guess history already knows it is wrong, and
+/// record the feedback in the history.
+///
+/// ## Arguments
+///
+/// * `client` - The client of the website
+/// * `history` - The previous guesses, saved again when the feedback is recorded
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+/// * `part` - The part of the puzzle
+/// * `answer` - The answer to send
+///
+/// ## Returns
+///
+/// * `Result<Feedback, SubmitError>` - The feedback of the website
+pub fn submit(
+    client: &Client,
+    history: &mut GuessHistory,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Feedback, SubmitError> {
+    history
+        .check(year, day, part, answer)
+        .map_err(SubmitError::Refused)?;
+
+    let answer = answer.to_string();
+    let level = part.to_string();
+    let page = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", &answer)],
+    )?;
+    let feedback = parse_feedback(&page);
+
+    let guess = Guess {
+        year,
+        day,
+        part,
+        answer,
+        feedback: feedback.clone(),
+    };
+    if history.record(guess) {
+        history.save()?;
+    }
+
+    Ok(feedback)
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            text
+        )
+    }
+
+    fn guess(answer: &str, feedback: Feedback) -> Guess {
+        Guess {
+            year: 2022,
+            day: 1,
+            part: Part::One,
+            answer: answer.to_string(),
+            feedback,
+        }
+    }
+
+    fn history(guesses: Vec<Guess>) -> GuessHistory {
+        GuessHistory {
+            path: PathBuf::from(GUESSES_FILE),
+            guesses,
+        }
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        let cases = [
+            ("That's the right answer!  You are one gold star closer.", Feedback::Correct),
+            ("That's not the right answer; your answer is too high.  If you're stuck, ...", Feedback::TooHigh),
+            ("That's not the right answer; your answer is too low.", Feedback::TooLow),
+            ("That's not the right answer.  If you're stuck, make sure ...", Feedback::Wrong),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait.",
+                Feedback::Wait { seconds: 64 },
+            ),
+            (
+                "You gave an answer too recently; ...  You have 34s left to wait.",
+                Feedback::Wait { seconds: 34 },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a>",
+                Feedback::AlreadySolved,
+            ),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_feedback(&page(text)), expected, "{}", text);
+        }
+
+        assert_eq!(
+            parse_feedback(&page("Something <em>else</em>\n happened.")),
+            Feedback::Unknown("Something else happened.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let history = history(vec![
+            guess("100", Feedback::TooHigh),
+            guess("20", Feedback::TooLow),
+            guess("50", Feedback::Wrong),
+            guess("80", Feedback::TooHigh),
+        ]);
+        let check = |answer: i128| history.check(2022, 1, Part::One, &Answer::Number(answer));
+
+        assert_eq!(check(60), Ok(()));
+        assert_eq!(
+            check(50),
+            Err(Refusal::AlreadyGuessed {
+                feedback: Feedback::Wrong
+            })
+        );
+        assert_eq!(
+            check(90),
+            Err(Refusal::AboveTooHigh {
+                bound: "80".to_string()
+            })
+        );
+        assert_eq!(
+            check(15),
+            Err(Refusal::BelowTooLow {
+                bound: "20".to_string()
+            })
+        );
+        assert_eq!(
+            history.check(2022, 1, Part::Two, &Answer::Number(90)),
+            Ok(())
+        );
+        assert_eq!(
+            history.check(2022, 1, Part::One, &Answer::Unsolved),
+            Err(Refusal::Unsolved)
+        );
+    }
+
+    #[test]
+    fn test_check_solved() {
+        let history = history(vec![
+            guess("ABC", Feedback::Wrong),
+            guess("XYZ", Feedback::Correct),
+        ]);
+
+        assert_eq!(
+            history.check(2022, 1, Part::One, &Answer::Text("DEF".to_string())),
+            Err(Refusal::AlreadySolved {
+                answer: "XYZ".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc_guesses_{}.toml", std::process::id()));
+        let mut saved = history(vec![guess("12", Feedback::TooLow)]);
+        saved.path = path.clone();
+        assert!(saved.record(guess("say \"hi\"", Feedback::Wrong)));
+        assert!(!saved.record(guess("13", Feedback::Wait { seconds: 30 })));
+        saved.save().unwrap();
+
+        let loaded = GuessHistory::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.guesses, saved.guesses);
+        assert!(matches!(
+            GuessHistory::parse("[[guess]]\nyear = 2022\n", &path),
+            Err(SubmitError::Invalid { index: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+//!
+//! # visualize
+//! Animation of the simulation-style puzzles in the terminal.
+//!
+//! A day opts in by returning a [`Visualize`] from `Solution::visualize`; `aoc run --visualize`
+//! then draws one frame per step, redrawing in place with ANSI escape codes.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::env;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::grid::Grid;
+use crate::image::{Image, Palette};
+use crate::point::Point;
+
+// Variables  =========================================================================== Variables
+/// Move the cursor to the top-left corner of the terminal.
+const CURSOR_HOME: &str = "\x1B[H";
+/// Clear the rest of the current line.
+const CLEAR_LINE: &str = "\x1B[K";
+/// Clear everything below the cursor.
+const CLEAR_BELOW: &str = "\x1B[J";
+/// Clear the whole terminal.
+const CLEAR_SCREEN: &str = "\x1B[2J";
+const HIDE_CURSOR: &str = "\x1B[?25l";
+const SHOW_CURSOR: &str = "\x1B[?25h";
+
+///
+/// # Visualize
+///
+/// A simulation that can be animated, one frame per step.
+pub trait Visualize {
+    ///
+    /// # step
+    ///
+    /// Advance the simulation to its next frame.
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - `false` when the simulation is over, the state being left unchanged
+    fn step(&mut self) -> bool;
+
+    ///
+    /// # render
+    ///
+    /// ## Returns
+    ///
+    /// * `Grid<char>` - The current state of the simulation
+    fn render(&self) -> Grid<char>;
+
+    ///
+    /// # focus
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Point<usize>>` - The position of the rendering to keep in view when it is
+    ///   larger than the terminal, `None` to show its top-left corner
+    fn focus(&self) -> Option<Point<usize>> {
+        None
+    }
+
+    ///
+    /// # image
+    ///
+    /// ## Returns
+    ///
+    /// * `Image` - The current state of the simulation as an image, one pixel per cell.
+    ///   Defaults to the rendering colored with the default palette.
+    fn image(&self) -> Image {
+        Image::from_chars(&self.render(), &Palette::default())
+    }
+}
+
+///
+/// # VisualizeOptions
+///
+/// How the frames are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VisualizeOptions {
+    /// Frames per second, `0` to draw them as fast as possible.
+    pub frame_rate: u32,
+    /// The number of columns shown.
+    pub width: usize,
+    /// The number of rows shown.
+    pub height: usize,
+}
+
+impl Default for VisualizeOptions {
+    fn default() -> Self {
+        VisualizeOptions {
+            frame_rate: 30,
+            width: 80,
+            height: 24,
+        }
+    }
+}
+
+impl VisualizeOptions {
+    ///
+    /// # from_env
+    ///
+    /// ## Returns
+    ///
+    /// * `VisualizeOptions` - The default options, sized after the `COLUMNS` and `LINES`
+    ///   environment variables when they are set
+    pub fn from_env() -> Self {
+        let size = |name: &str| env::var(name).ok().and_then(|value| value.parse().ok());
+        let default = VisualizeOptions::default();
+
+        VisualizeOptions {
+            width: size("COLUMNS").unwrap_or(default.width),
+            // Keep the last line free for the cursor
+            height: size("LINES").map_or(default.height, |lines: usize| lines.saturating_sub(1)),
+            ..default
+        }
+    }
+}
+
+///
+/// # Viewport
+///
+/// The part of a frame that fits in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    ///
+    /// # around
+    ///
+    /// ## Arguments
+    ///
+    /// * `frame` - The whole frame
+    /// * `focus` - The position to keep in view, `None` for the top-left corner
+    /// * `width` - The maximum number of columns shown
+    /// * `height` - The maximum number of rows shown
+    ///
+    /// ## Returns
+    ///
+    /// * `Viewport` - The area centered on the focus, moved back inside the frame
+    pub fn around(
+        frame: &Grid<char>,
+        focus: Option<Point<usize>>,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        let width = width.min(frame.width());
+        let height = height.min(frame.height());
+        let focus = focus.unwrap_or(Point::origin());
+
+        Viewport {
+            x: focus.x.saturating_sub(width / 2).min(frame.width() - width),
+            y: focus
+                .y
+                .saturating_sub(height / 2)
+                .min(frame.height() - height),
+            width,
+            height,
+        }
+    }
+
+    ///
+    /// # crop
+    ///
+    /// ## Arguments
+    ///
+    /// * `frame` - The whole frame
+    ///
+    /// ## Returns
+    ///
+    /// * `String` - The rows of the frame inside the viewport
+    pub fn crop(&self, frame: &Grid<char>) -> String {
+        (self.y..self.y + self.height)
+            .map(|y| {
+                frame.row(y)[self.x..self.x + self.width]
+                    .iter()
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+///
+/// # Terminal
+///
+/// Draws frames over each other, at a steady frame rate.
+pub struct Terminal<W: Write> {
+    out: W,
+    frame_duration: Option<Duration>,
+    last_frame: Option<Instant>,
+}
+
+impl<W: Write> Terminal<W> {
+    ///
+    /// # new
+    ///
+    /// ## Arguments
+    ///
+    /// * `out` - Where the frames are written, usually the standard output
+    /// * `frame_rate` - Frames per second, `0` for no limit
+    pub fn new(out: W, frame_rate: u32) -> Self {
+        Terminal {
+            out,
+            frame_duration: (frame_rate > 0).then(|| Duration::from_secs(1) / frame_rate),
+            last_frame: None,
+        }
+    }
+
+    ///
+    /// # start
+    ///
+    /// Clear the terminal and hide the cursor before the first frame.
+    pub fn start(&mut self) -> io::Result<()> {
+        write!(self.out, "{}{}", HIDE_CURSOR, CLEAR_SCREEN)?;
+        self.out.flush()
+    }
+
+    ///
+    /// # draw
+    ///
+    /// Draw a frame over the previous one, waiting for the frame rate if needed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `frame` - The lines to draw
+    pub fn draw(&mut self, frame: &str) -> io::Result<()> {
+        if let (Some(duration), Some(last_frame)) = (self.frame_duration, self.last_frame) {
+            if let Some(remaining) = duration.checked_sub(last_frame.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+
+        write!(self.out, "{}", CURSOR_HOME)?;
+        for line in frame.lines() {
+            writeln!(self.out, "{}{}", line, CLEAR_LINE)?;
+        }
+        write!(self.out, "{}", CLEAR_BELOW)?;
+        self.out.flush()?;
+
+        self.last_frame = Some(Instant::now());
+        Ok(())
+    }
+
+    ///
+    /// # finish
+    ///
+    /// Give the cursor back after the last frame.
+    pub fn finish(&mut self) -> io::Result<()> {
+        write!(self.out, "{}", SHOW_CURSOR)?;
+        self.out.flush()
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # animate
+///
+/// Run a simulation until it is over, drawing every step.
+///
+/// ## Arguments
+///
+/// * `simulation` - The simulation to animate
+/// * `options` - The frame rate and the size of the terminal
+/// * `out` - Where the frames are written
+///
+/// ## Returns
+///
+/// * `io::Result<usize>` - The number of frames drawn
+pub fn animate(
+    simulation: &mut dyn Visualize,
+    options: &VisualizeOptions,
+    out: impl Write,
+) -> io::Result<usize> {
+    let mut terminal = Terminal::new(out, options.frame_rate);
+    let mut frames = 0;
+
+    terminal.start()?;
+    loop {
+        let frame = simulation.render();
+        let viewport = Viewport::around(&frame, simulation.focus(), options.width, options.height);
+        terminal.draw(&viewport.crop(&frame))?;
+        frames += 1;
+
+        if !simulation.step() {
+            break;
+        }
+    }
+    terminal.finish()?;
+
+    Ok(frames)
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A dot moving to the right of a 10×3 frame.
+    struct Dot {
+        x: usize,
+    }
+
+    impl Visualize for Dot {
+        fn step(&mut self) -> bool {
+            if self.x == 9 {
+                return false;
+            }
+            self.x += 1;
+            true
+        }
+
+        fn render(&self) -> Grid<char> {
+            Grid::from_fn(10, 3, |point| {
+                if point == Point::new(self.x, 1) {
+                    'o'
+                } else {
+                    '.'
+                }
+            })
+        }
+
+        fn focus(&self) -> Option<Point<usize>> {
+            Some(Point::new(self.x, 1))
+        }
+    }
+
+    #[test]
+    fn test_viewport_around() {
+        let frame = Grid::new(10, 3, '.');
+
+        assert_eq!(
+            Viewport::around(&frame, Some(Point::new(5, 1)), 4, 2),
+            Viewport {
+                x: 3,
+                y: 0,
+                width: 4,
+                height: 2
+            }
+        );
+        // Moved back inside the frame
+        assert_eq!(Viewport::around(&frame, Some(Point::new(9, 2)), 4, 2).x, 6);
+        // Larger than the frame
+        assert_eq!(
+            Viewport::around(&frame, None, 80, 24),
+            Viewport {
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_viewport_crop() {
+        let frame: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let viewport = Viewport {
+            x: 1,
+            y: 1,
+            width: 2,
+            height: 2,
+        };
+
+        assert_eq!(viewport.crop(&frame), "fg\njk");
+    }
+
+    #[test]
+    fn test_terminal_draw() {
+        let mut out = Vec::new();
+        let mut terminal = Terminal::new(&mut out, 0);
+        terminal.draw("ab\ncd").unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1B[Hab\x1B[K\ncd\x1B[K\n\x1B[J"
+        );
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut out = Vec::new();
+        let options = VisualizeOptions {
+            frame_rate: 0,
+            width: 3,
+            height: 1,
+        };
+
+        let frames = animate(&mut Dot { x: 0 }, &options, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(frames, 10);
+        assert!(out.starts_with(HIDE_CURSOR));
+        assert!(out.ends_with(SHOW_CURSOR));
+        // The viewport follows the dot
+        assert!(out.contains("\x1B[H.o.\x1B[K\n"));
+        assert!(out.contains("\x1B[H..o\x1B[K\n"));
+    }
+}
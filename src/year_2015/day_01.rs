@@ -0,0 +1,55 @@
+//!
+//! # day_01.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2015, 1, Day01);
+// Functions  =========================================================================== Functions
+impl Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        // Count the number of open and close parenthesis
+        let mut open_parenthesis: i32 = 0;
+        let mut close_parenthesis: i32 = 0;
+        for c in input.chars() {
+            match c {
+                '(' => open_parenthesis += 1,
+                ')' => close_parenthesis += 1,
+                _ => (),
+            }
+        }
+
+        (open_parenthesis - close_parenthesis).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        // Count the number of open and close parenthesis
+        let mut open_parenthesis: i32 = 0;
+        let mut close_parenthesis: i32 = 0;
+
+        for (i, c) in input.chars().enumerate() {
+            match c {
+                '(' => open_parenthesis += 1,
+                ')' => close_parenthesis += 1,
+                _ => (),
+            }
+
+            if open_parenthesis - close_parenthesis == -1 {
+                return (i + 1).into();
+            }
+        }
+
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,85 @@
+//!
+//! # day_02.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2015, 2, Day02);
+
+// Functions  =========================================================================== Functions
+///
+/// # calc_surface_area
+/// Calculates the surface area of a box.
+///
+/// ## Arguments
+/// * `l` - The length of the box
+/// * `w` - The width of the box
+/// * `h` - The height of the box
+///
+/// ## Returns
+/// * `u32` - The surface area of the box
+fn calc_surface_area(l: u32, w: u32, h: u32) -> u32 {
+	2 * l * w + 2 * w * h + 2 * h * l
+}
+
+///
+/// # calc_cubic_feet
+/// Calculates the cubic feet of a box.
+///
+/// ## Arguments
+/// * `l` - The length of the box
+/// * `w` - The width of the box
+/// * `h` - The height of the box
+///
+/// ## Returns
+/// * `u32` - The cubic feet of the box
+fn calc_cubic_feet(l: u32, w: u32, h: u32) -> u32 {
+	l * w * h
+}
+// Main  ====================================================================================  Main
+impl Solution for Day02 {
+	// The dimensions of each box, sorted from the smallest to the largest.
+	type Input = Vec<Vec<u32>>;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		parse::lines_with(input, |line| {
+			let mut dimensions = line
+				.split('x')
+				.map(|x| parse::number::<u32>(line, x))
+				.collect::<Result<Vec<u32>, AocError>>()?;
+
+			if dimensions.len() != 3 {
+				return Err(AocError::new("expected the dimensions of a box as `LxWxH`"));
+			}
+
+			dimensions.sort();
+			Ok(dimensions)
+		})
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		let mut total_paper: u32 = 0;
+
+		for dimensions in input {
+			total_paper += calc_surface_area(dimensions[0], dimensions[1], dimensions[2]) + dimensions[0] * dimensions[1];
+		}
+
+		total_paper.into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		let mut total_ribbon: u32 = 0;
+
+		for dimensions in input {
+			total_ribbon += 2 * dimensions[0] + 2 * dimensions[1] + calc_cubic_feet(dimensions[0], dimensions[1], dimensions[2]);
+		}
+
+		total_ribbon.into()
+	}
+}
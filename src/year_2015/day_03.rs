@@ -0,0 +1,66 @@
+//!
+//! # day_03.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2015, 3, Day03);
+// Functions  =========================================================================== Functions
+impl Solution for Day03 {
+	type Input = String;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		Ok(input.to_string())
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		let mut houses = std::collections::HashSet::new();
+		let mut x = 0;
+		let mut y = 0;
+		houses.insert((x, y)); // The first house is always visited
+
+		for c in input.chars() {
+			match c {
+				'<' => x -= 1,
+				'>' => x += 1,
+				'^' => y += 1,
+				'v' => y -= 1,
+				_ => (),
+			}
+			houses.insert((x, y));
+		}
+
+		houses.len().into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		let mut houses = std::collections::HashSet::new();
+		let mut santa_x = 0;
+		let mut santa_y = 0;
+		let mut robot_x = 0;
+		let mut robot_y = 0;
+		houses.insert((santa_x, santa_y)); // The first house is always visited
+
+		for (i, c) in input.chars().enumerate() {
+			match c {
+				'<' => if i % 2 == 0 { santa_x -= 1 } else { robot_x -= 1 },
+				'>' => if i % 2 == 0 { santa_x += 1 } else { robot_x += 1 },
+				'^' => if i % 2 == 0 { santa_y += 1 } else { robot_y += 1 },
+				'v' => if i % 2 == 0 { santa_y -= 1 } else { robot_y -= 1 },
+				_ => (),
+			}
+			if i % 2 == 0 {
+				houses.insert((santa_x, santa_y));
+			} else {
+				houses.insert((robot_x, robot_y));
+			}
+		}
+
+		houses.len().into()
+	}
+}
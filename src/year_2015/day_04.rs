@@ -0,0 +1,53 @@
+//!
+//! # day_04.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+use md5::compute;
+// Variables  =========================================================================== Variables
+aoc_day!(2015, 4, Day04);
+
+// Functions  =========================================================================== Functions
+///
+/// # find_lowest_number
+/// Finds the lowest number which, appended to the secret key, gives an MD5 hash
+/// starting with the given prefix.
+///
+/// ## Arguments
+/// * `key` - The secret key
+/// * `prefix` - The prefix the hexadecimal hash must start with
+///
+/// ## Returns
+/// * `u32` - The lowest number producing such a hash
+fn find_lowest_number(key: &str, prefix: &str) -> u32 {
+	let mut i = 0;
+	loop {
+		let input = format!("{}{}", key, i);
+		let digest = compute(input);
+		let hex = format!("{:x}", digest);
+		if hex.starts_with(prefix) {
+			return i;
+		}
+		i += 1;
+	}
+}
+
+impl Solution for Day04 {
+	type Input = String;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		Ok(input.trim().to_string())
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		find_lowest_number(input, "00000").into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		find_lowest_number(input, "000000").into()
+	}
+}
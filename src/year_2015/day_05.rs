@@ -1,15 +1,18 @@
-///
-/// # day_05.rs
-/// Code for the day 01 of the Advent of Code challenge year 2015
-///
+//!
+//! # day_05.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
 
 // Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../../data/year_2015/inputs/day_05.txt");
-
 const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
 const FORBIDDEN: [&str; 4] = ["ab", "cd", "pq", "xy"];
+
+aoc_day!(2015, 5, Day05);
 // Functions  =========================================================================== Functions
 fn string_contains_three_vowels(word: &str) -> bool {
 	let mut cpt: usize = 0;
@@ -36,15 +39,6 @@ fn evaluate_string(word: &str) -> bool {
 	string_contains_three_vowels(word) && string_contains_double_letter(word) && !string_contains_forbidden(word)
 }
 
-pub fn response_part_1() {
-	println!("Day 05 - Part 1");
-
-	let cpt = INPUT.lines().map(|l| evaluate_string(l)).filter(|b| *b).count();
-
-	print!("There are {} nice strings.", cpt);
-
-}
-
 // Part 2 functions
 fn string_contains_double_pair(word: &str) -> bool {
 	word.as_bytes().windows(2).enumerate().any(|(i, w)| {
@@ -57,29 +51,39 @@ fn string_contains_sandwich(word: &str) -> bool {
 	word.as_bytes().windows(3).any(|w| w[0] == w[2])
 }
 
-pub fn response_part_2() {
-	println!("Day 05 - Part 2");
+impl Solution for Day05 {
+	type Input = Vec<String>;
 
-	let cpt = INPUT.lines()
-		.map(|l| string_contains_double_pair(l) && string_contains_sandwich(l))
-		.filter(|b| *b).count();
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		Ok(input.lines().map(|l| l.to_string()).collect())
+	}
 
-	print!("There are {} nice strings.", cpt);
+	fn part_one(input: &Self::Input) -> Answer {
+		input.iter().filter(|l| evaluate_string(l)).count().into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		input
+			.iter()
+			.filter(|l| string_contains_double_pair(l) && string_contains_sandwich(l))
+			.count()
+			.into()
+	}
 }
 
 // Tests ==================================================================================== Tests
 #[test]
 fn test_word_contains_three_vowels() {
-	assert_eq!(string_contains_three_vowels("aei"), true);
-	assert_eq!(string_contains_three_vowels("xazegov"), true);
-	assert_eq!(string_contains_three_vowels("aeiouaeiouaeiou"), true);
-	assert_eq!(string_contains_three_vowels("dvszwmarrgswjxmb"), false);
+	assert!(string_contains_three_vowels("aei"));
+	assert!(string_contains_three_vowels("xazegov"));
+	assert!(string_contains_three_vowels("aeiouaeiouaeiou"));
+	assert!(!string_contains_three_vowels("dvszwmarrgswjxmb"));
 }
 
 #[test]
 fn test_word_contains_double_letter() {
-	assert_eq!(string_contains_double_letter("xx"), true);
-	assert_eq!(string_contains_double_letter("abcdde"), true);
-	assert_eq!(string_contains_double_letter("aabbccdd"), true);
-	assert_eq!(string_contains_double_letter("jchzalrnumimnmhp"), false);
+	assert!(string_contains_double_letter("xx"));
+	assert!(string_contains_double_letter("abcdde"));
+	assert!(string_contains_double_letter("aabbccdd"));
+	assert!(!string_contains_double_letter("jchzalrnumimnmhp"));
 }
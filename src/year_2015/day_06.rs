@@ -0,0 +1,212 @@
+//!
+//! # day_06.rs
+//! Code for the day 01 of the Advent of Code challenge year 2015
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::image::Image;
+use crate::parse;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+use crate::visualize::Visualize;
+use std::cmp::{max, min};
+use std::str::FromStr;
+// Variables  =========================================================================== Variables
+const ARRAY_SIZE: u64 = 1000;
+
+/// The symbols of the brightness in the terminal, from off to the brightest light.
+const SHADES: [char; 10] = [' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+aoc_day!(2015, 6, Day06);
+
+trait LightBehaviour {
+	fn toggle(&mut self);
+	fn turn_on(&mut self);
+	fn turn_off(&mut self);
+}
+// Functions  =========================================================================== Functions
+impl LightBehaviour for bool {
+	fn toggle(&mut self) {
+		*self = !*self;
+	}
+
+	fn turn_on(&mut self) {
+		*self = true;
+	}
+
+	fn turn_off(&mut self) {
+		*self = false;
+	}
+}
+
+impl LightBehaviour for u64 {
+	fn toggle(&mut self) {
+		*self += 2;
+	}
+
+	fn turn_on(&mut self) {
+		*self += 1;
+	}
+
+	fn turn_off(&mut self) {
+		if *self > 0 {
+			*self -= 1;
+		}
+	}
+}
+
+///
+/// # Action
+/// What an instruction does to the lights of its rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+	TurnOn,
+	Toggle,
+	TurnOff,
+}
+
+///
+/// # Instruction
+/// An instruction of Santa, in the following format:
+/// - turn on 0,0 through 999,999
+/// - toggle 0,0 through 999,0
+/// - turn off 499,499 through 500,500
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+	action: Action,
+	from: (usize, usize),
+	to: (usize, usize),
+}
+
+impl FromStr for Instruction {
+	type Err = AocError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let actions = [
+			("turn on ", Action::TurnOn),
+			("toggle ", Action::Toggle),
+			("turn off ", Action::TurnOff),
+		];
+
+		let (action, rest) = actions
+			.into_iter()
+			.find_map(|(prefix, action)| Some((action, s.strip_prefix(prefix)?)))
+			.ok_or_else(|| AocError::new("expected `turn on`, `toggle` or `turn off`").at_column(1))?;
+
+		let (x1, y1, x2, y2) = scan!(rest, "{},{} through {},{}" => &str, &str, &str, &str)
+			.map_err(|error| error.within(s, rest))?;
+		let coordinate = |token: &str| -> Result<usize, AocError> {
+			let value = parse::number::<usize>(s, token)?;
+
+			if value >= ARRAY_SIZE as usize {
+				return Err(AocError::new(format!("coordinate {} is outside of the grid", value))
+					.at_token(s, token));
+			}
+
+			Ok(value)
+		};
+
+		Ok(Instruction {
+			action,
+			from: (coordinate(x1)?, coordinate(y1)?),
+			to: (coordinate(x2)?, coordinate(y2)?),
+		})
+	}
+}
+
+///
+/// # apply_instruction
+/// Applies the given instruction to the lights.
+///
+/// ## Arguments
+/// * `lights` - The grid of lights
+/// * `instruction` - The instruction to apply
+fn apply_instruction<T: LightBehaviour>(
+	lights: &mut Grid<T>,
+	instruction: &Instruction
+) {
+	let (x1, y1) = instruction.from;
+	let (x2, y2) = instruction.to;
+
+	for y in min(y1, y2)..=max(y1, y2) {
+		for light in &mut lights.row_mut(y)[min(x1, x2)..=max(x1, x2)] {
+			match instruction.action {
+				Action::TurnOn => light.turn_on(),
+				Action::Toggle => light.toggle(),
+				Action::TurnOff => light.turn_off(),
+			}
+		}
+	}
+}
+
+///
+/// # LightsAnimation
+/// The brightness of the lights of the second part, one instruction per frame.
+pub struct LightsAnimation {
+	instructions: Vec<Instruction>,
+	lights: Grid<u64>,
+	applied: usize,
+}
+
+impl Visualize for LightsAnimation {
+	fn step(&mut self) -> bool {
+		let Some(instruction) = self.instructions.get(self.applied) else {
+			return false;
+		};
+
+		apply_instruction(&mut self.lights, instruction);
+		self.applied += 1;
+		true
+	}
+
+	fn render(&self) -> Grid<char> {
+		let brightest = self.lights.values().max().copied().unwrap_or_default().max(1);
+
+		self.lights.map(|&light| {
+			SHADES[(light * (SHADES.len() as u64 - 1)).div_ceil(brightest) as usize]
+		})
+	}
+
+	fn image(&self) -> Image {
+		Image::heightmap(&self.lights, |&light| light as f64)
+	}
+}
+
+impl Solution for Day06 {
+	type Input = Vec<Instruction>;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		parse::lines(input)
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		let mut lights = Grid::new(ARRAY_SIZE as usize, ARRAY_SIZE as usize, false);
+
+		for instruction in input {
+			apply_instruction(&mut lights, instruction);
+		}
+
+		lights.values().filter(|&light| *light).count().into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		let mut lights = Grid::new(ARRAY_SIZE as usize, ARRAY_SIZE as usize, 0u64);
+
+		for instruction in input {
+			apply_instruction(&mut lights, instruction);
+		}
+
+		lights.values().sum::<u64>().into()
+	}
+
+	fn visualize(input: &Self::Input) -> Option<Box<dyn Visualize>> {
+		Some(Box::new(LightsAnimation {
+			instructions: input.clone(),
+			lights: Grid::new(ARRAY_SIZE as usize, ARRAY_SIZE as usize, 0),
+			applied: 0,
+		}))
+	}
+}
@@ -0,0 +1,11 @@
+//!
+//! # year_2015
+//! Solutions of the Advent of Code challenge year 2015
+//!
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
@@ -0,0 +1,42 @@
+//!
+//! # day_01.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 1, Day01);
+
+// Functions  =========================================================================== Functions
+impl Solution for Day01 {
+    // The sum of the numbers of each group.
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // The input is a list of groups of numbers, each group separated by two newlines.
+        // Each group is a list of numbers separated by newlines.
+        parse::blocks(input.trim_end(), |group| {
+            let numbers = parse::lines_with(group, |num| parse::number::<i32>(num, num))?;
+            Ok(numbers.iter().sum())
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        // We need to return the biggest sum.
+        input.iter().max().copied().into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        // Here, we need the three biggests groups of numbers and return the sum of the numbers in each group.
+        let mut max_sum = input.clone();
+        max_sum.sort();
+        max_sum.reverse();
+
+        max_sum.iter().take(3).sum::<i32>().into()
+    }
+}
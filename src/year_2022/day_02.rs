@@ -0,0 +1,95 @@
+//!
+//! # day_02.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 2, Day02);
+
+// Functions  =========================================================================== Functions
+fn score_for_round(opponent_moove: char, player_moove: char) -> u32 {
+    match (opponent_moove, player_moove) {
+        ('A', 'Y') | ('B', 'Z') | ('C', 'X') => 6,
+        ('A', 'X') | ('B', 'Y') | ('C', 'Z') => 3,
+        ('A', 'Z') | ('B', 'X') | ('C', 'Y') => 0,
+        _ => panic!("Invalid choice made"),
+    }
+}
+
+fn score_for_player_moove(player_moove: char) -> u32 {
+    match player_moove {
+        'X' => 1,
+        'Y' => 2,
+        'Z' => 3,
+        _ => panic!("Invalid choice made"),
+    }
+}
+
+fn choose_best_moove(opponent_moove: char, what_to_do: char) -> char {
+    match (what_to_do, opponent_moove) {
+        ('X', 'A') => 'Z',
+        ('X', 'B') => 'X',
+        ('X', 'C') => 'Y',
+        ('Y', 'A') => 'X',
+        ('Y', 'B') => 'Y',
+        ('Y', 'C') => 'Z',
+        ('Z', 'A') => 'Y',
+        ('Z', 'B') => 'Z',
+        ('Z', 'C') => 'X',
+        _ => panic!(
+            "Invalid choice p;rovided, what to do: {} - {}",
+            opponent_moove, what_to_do
+        ),
+    }
+}
+
+impl Solution for Day02 {
+    // The opponent moove and the second column of each round.
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines_with(input, |line| {
+            let (opponent_moove, second) = scan!(line, "{} {}" => char, char)?;
+
+            if !('A'..='C').contains(&opponent_moove) {
+                return Err(AocError::new("expected A, B or C").at_column(1));
+            }
+            if !('X'..='Z').contains(&second) {
+                return Err(AocError::new("expected X, Y or Z").at_column(3));
+            }
+
+            Ok((opponent_moove, second))
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        // The second column is the moove to play.
+        input
+            .iter()
+            .map(|&(opponent_moove, player_moove)| {
+                score_for_round(opponent_moove, player_moove) + score_for_player_moove(player_moove)
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        // The second column is what to do: lose, draw or win.
+        input
+            .iter()
+            .map(|&(opponent_moove, what_to_do)| {
+                let player_moove = choose_best_moove(opponent_moove, what_to_do);
+
+                score_for_round(opponent_moove, player_moove) + score_for_player_moove(player_moove)
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
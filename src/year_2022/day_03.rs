@@ -0,0 +1,152 @@
+//!
+//! # day_03.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 3, Day03);
+
+// Functions  =========================================================================== Functions
+///
+/// # get_priority
+///
+/// Get the priority of a character
+/// The priority is calculated as follows:
+/// - a..z: 1..26
+/// - A..Z: 27..52
+///
+/// ## Arguments
+///
+/// * `c` - The character to get the priority
+///
+/// ## Returns
+///
+/// * `u32` - The priority of the character
+fn get_priority(c: char) -> u32 {
+    match c {
+        // chars from a..z
+        'a'..='z' => c as u32 - 'a' as u32 + 1,
+
+        // chars from A..Z
+        'A'..='Z' => c as u32 - 'A' as u32 + 27,
+        _ => 0,
+    }
+}
+
+///
+/// # common_item
+///
+/// ## Arguments
+///
+/// * `parts` - The compartments of a rucksack, or the rucksacks of a group
+///
+/// ## Returns
+///
+/// * `Option<char>` - The item found in every part, `None` if there isn't any
+fn common_item(parts: &[&str]) -> Option<char> {
+    let (first, others) = parts.split_first()?;
+    let others: Vec<HashSet<char>> = others.iter().map(|part| part.chars().collect()).collect();
+
+    first
+        .chars()
+        .find(|item| others.iter().all(|set| set.contains(item)))
+}
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines_with(input, |line| {
+            if let Some(column) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(AocError::new("items are letters").at_column(column + 1));
+            }
+            if line.len() % 2 != 0 {
+                return Err(AocError::new("the compartments don't have the same size"));
+            }
+            let (first, second) = line.split_at(line.len() / 2);
+            if common_item(&[first, second]).is_none() {
+                return Err(AocError::new("no item is in both compartments"));
+            }
+
+            Ok(line.to_string())
+        })
+        .and_then(|rucksacks: Vec<String>| {
+            let lines: Vec<&str> = input.lines().collect();
+            if !rucksacks.len().is_multiple_of(3) {
+                let last = rucksacks.len();
+                return Err(AocError::new("the last group doesn't have 3 elves")
+                    .at_line(last, lines[last - 1]));
+            }
+            for (index, group) in rucksacks.chunks(3).enumerate() {
+                let group: Vec<&str> = group.iter().map(String::as_str).collect();
+                if common_item(&group).is_none() {
+                    return Err(
+                        AocError::new("no badge is carried by the 3 elves of the group")
+                            .at_line(index * 3 + 1, lines[index * 3]),
+                    );
+                }
+            }
+
+            Ok(rucksacks)
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|line| {
+                // split the line in two compartments
+                let (first, second) = line.split_at(line.len() / 2);
+
+                // get the priority of the common item
+                get_priority(common_item(&[first, second]).expect("checked by `parse`"))
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        input
+            .chunks(3) // split the rucksacks in groups of 3
+            .map(|group| {
+                let group: Vec<&str> = group.iter().map(String::as_str).collect();
+
+                // get the priority of the badge carried by the whole group
+                get_priority(common_item(&group).expect("checked by `parse`"))
+            })
+            .sum::<u32>()
+            .into()
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day03::parse("ab").err().unwrap();
+        assert_eq!(error.message(), "no item is in both compartments");
+        assert_eq!(error.line(), Some(1));
+
+        let error = Day03::parse("aa\nbb").err().unwrap();
+        assert_eq!(error.message(), "the last group doesn't have 3 elves");
+        assert_eq!(error.line(), Some(2));
+
+        let error = Day03::parse("aa\naa\nbb").err().unwrap();
+        assert_eq!(
+            error.message(),
+            "no badge is carried by the 3 elves of the group"
+        );
+        assert_eq!(error.line(), Some(1));
+
+        assert!(Day03::parse("aa\nabab\naa").is_ok());
+    }
+}
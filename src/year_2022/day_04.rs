@@ -0,0 +1,85 @@
+//!
+//! # day_04.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse::{self, FromInput};
+use crate::scan;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 4, Day04);
+
+///
+/// Represents a range of section IDs, in the format "start-end"
+///
+/// ## Fields
+/// * `start` - The starting section ID of the range
+/// * `end` - The ending section ID of the range
+#[derive(FromInput)]
+#[input("{start}-{end}")]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+///
+/// Implements methods for the Range struct
+impl Range {
+    ///
+    /// Checks if this range fully contains another range
+    ///
+    /// # Arguments
+    /// * `other` - The other range to check against
+    ///
+    /// # Returns
+    /// `true` if this range fully contains the other range, `false` otherwise
+    fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    ///
+    /// Checks if this range overlaps with another range
+    ///
+    /// # Arguments
+    /// * `other` - The other range to check against
+    ///
+    /// # Returns
+    /// `true` if there is any overlap between the ranges, `false` otherwise
+    fn overlaps(&self, other: &Range) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Range, Range)>, AocError> {
+    parse::lines_with(input, |line| scan!(line, "{},{}" => Range, Range))
+}
+
+// Functions  =========================================================================== Functions
+impl Solution for Day04 {
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            // Filter out pairs where one range fully contains the other
+            .filter(|(a, b)| a.contains(b) || b.contains(a))
+            .count()
+            .into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            // Filter out pairs where there is no overlap
+            .filter(|(a, b)| a.overlaps(b))
+            .count()
+            .into()
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! # day_05.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::collections::VecDeque;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 5, Day05);
+
+/// A move of `count` crates from one stack to another, both 0-indexed.
+type Move = (usize, usize, usize);
+
+// Functions  =========================================================================== Functions
+///
+/// # parse_input
+///
+/// Parse the input string and return a tuple with the stacks and moves.
+///
+/// ## Arguments
+///
+/// * `input` - The input string.
+///
+/// ## Returns
+///
+/// * A tuple with the stacks and moves, or where the input is malformed.
+fn parse_input(input: &str) -> Result<(Vec<VecDeque<char>>, Vec<Move>), AocError> {
+    let (stacks_str, moves_str) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::new("expected the stacks and the moves separated by a blank line")
+    })?;
+
+    // Parse stacks, the last line holds their labels
+    let stacks_count = stacks_str
+        .lines()
+        .last()
+        .unwrap_or_default()
+        .split_whitespace()
+        .count();
+    let mut stacks: Vec<VecDeque<char>> = vec![VecDeque::new(); stacks_count];
+    let crate_lines = stacks_str.lines().count().saturating_sub(1);
+    for (index, line) in stacks_str.lines().enumerate().take(crate_lines) {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                stacks
+                    .get_mut(i)
+                    .ok_or_else(|| {
+                        AocError::new("crate outside of the stacks")
+                            .at_column(i * 4 + 2)
+                            .at_line(index + 1, line)
+                    })?
+                    .push_back(c);
+            }
+        }
+    }
+
+    // Parse moves
+    let moves = parse::lines_with(moves_str, |line| parse_move(line, stacks_count))
+        .map_err(|error| error.offset_lines(stacks_str.lines().count() + 1))?;
+
+    Ok((stacks, moves))
+}
+
+///
+/// # parse_move
+///
+/// Parse a move, in the format "move 3 from 1 to 2".
+///
+/// ## Arguments
+///
+/// * `line` - The move to parse.
+/// * `stacks_count` - The number of stacks.
+///
+/// ## Returns
+///
+/// * The move, with 0-indexed stacks.
+fn parse_move(line: &str, stacks_count: usize) -> Result<Move, AocError> {
+    let words: Vec<&str> = line.split(' ').collect();
+    let ["move", count, "from", from, "to", to] = words[..] else {
+        return Err(AocError::new("expected `move N from X to Y`"));
+    };
+
+    let stack = |token: &str| -> Result<usize, AocError> {
+        match parse::number::<usize>(line, token)? {
+            stack @ 1.. if stack <= stacks_count => Ok(stack - 1), // 1-indexed to 0-indexed
+            stack => {
+                Err(AocError::new(format!("there is no stack {}", stack)).at_token(line, token))
+            }
+        }
+    };
+
+    Ok((parse::number(line, count)?, stack(from)?, stack(to)?))
+}
+
+///
+/// # execute_moves
+///
+/// Execute the moves on the stacks and return the resulting top crates.
+///
+/// ## Arguments
+///
+/// * `stacks` - A vector of VecDeque<char> representing the initial stacks of crates.
+/// * `moves` - A slice of tuples (count, from, to) representing the moves to execute.
+///
+/// ## Returns
+///
+/// * A String containing the top crates of each stack after executing all moves.
+fn execute_moves_9000(mut stacks: Vec<VecDeque<char>>, moves: &[Move]) -> String {
+    for &(count, from, to) in moves {
+        for _ in 0..count {
+            if let Some(crate_) = stacks[from].pop_front() {
+                stacks[to].push_front(crate_);
+            }
+        }
+    }
+
+    // This line collects the top crates from each stack into a String.
+    // It iterates over the stacks, uses filter_map to get the first (top) crate of each non-empty stack,
+    // and then collects these characters into a String, which represents the final arrangement.
+    stacks.iter().filter_map(|stack| stack.front()).collect()
+}
+
+///
+/// # execute_moves_9001
+///
+/// Execute the moves on the stacks using the CrateMover 9001 and return the resulting top crates.
+///
+/// ## Arguments
+///
+/// * `stacks` - A vector of VecDeque<char> representing the initial stacks of crates.
+/// * `moves` - A slice of tuples (count, from, to) representing the moves to execute.
+///
+/// ## Returns
+///
+/// * A String containing the top crates of each stack after executing all moves.
+fn execute_moves_9001(mut stacks: Vec<VecDeque<char>>, moves: &[Move]) -> String {
+    for &(count, from, to) in moves {
+        let mut temp_stack = VecDeque::new();
+        for _ in 0..count {
+            if let Some(crate_) = stacks[from].pop_front() {
+                temp_stack.push_front(crate_);
+            }
+        }
+        for crate_ in temp_stack {
+            stacks[to].push_front(crate_);
+        }
+    }
+
+    stacks.iter().filter_map(|stack| stack.front()).collect()
+}
+
+impl Solution for Day05 {
+    type Input = (Vec<VecDeque<char>>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one((stacks, moves): &Self::Input) -> Answer {
+        execute_moves_9000(stacks.clone(), moves).into()
+    }
+
+    fn part_two((stacks, moves): &Self::Input) -> Answer {
+        execute_moves_9001(stacks.clone(), moves).into()
+    }
+}
@@ -1,12 +1,15 @@
-///
-/// # day_06.rs
-/// Code for the day 01 of the Advent of Code challenge year 2022
-///
+//!
+//! # day_06.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
 // Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
 use std::collections::HashSet;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../../data/year_2022/inputs/day_06.txt");
+aoc_day!(2022, 6, Day06);
 
 // Functions  =========================================================================== Functions
 ///
@@ -37,18 +40,18 @@ fn find_marker(input: &str, marker_size: usize) -> Option<usize> {
     None
 }
 
-pub fn response_part_1() {
-    if let Some(result) = find_marker(INPUT, 4) {
-        println!("Day 06 - Part 1: {}", result);
-    } else {
-        println!("Day 06 - Part 1: No marker found");
+impl Solution for Day06 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        find_marker(input, 4).into()
     }
-}
 
-pub fn response_part_2() {
-    if let Some(result) = find_marker(INPUT, 14) {
-        println!("Day 06 - Part 1: {}", result);
-    } else {
-        println!("Day 06 - Part 1: No marker found");
+    fn part_two(input: &Self::Input) -> Answer {
+        find_marker(input, 14).into()
     }
 }
@@ -0,0 +1,159 @@
+//!
+//! # day_07.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 7, Day07);
+
+///
+/// # FileSystem
+///
+/// Simulate a file system.
+pub struct FileSystem {
+    directories: HashMap<String, Directory>,
+}
+
+///
+/// # Directory
+///
+/// Represent a directory in the file system.
+struct Directory {
+    size: usize,
+    parent: Option<String>,
+}
+
+impl FileSystem {
+    fn new() -> Self {
+        let mut fs = FileSystem {
+            directories: HashMap::new(),
+        };
+        fs.directories.insert(
+            "/".to_string(),
+            Directory {
+                size: 0,
+                parent: None,
+            },
+        );
+        fs
+    }
+
+    ///
+    /// # add_file
+    ///
+    /// Add a file to the file system.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The path of the file.
+    /// * `size` - The size of the file.
+    ///
+    fn add_file(&mut self, path: &str, size: usize) {
+        let mut current = path.to_string();
+        while let Some(dir) = self.directories.get_mut(&current) {
+            dir.size += size;
+            if let Some(parent) = &dir.parent {
+                current = parent.clone();
+            } else {
+                break;
+            }
+        }
+    }
+
+    ///
+    /// # add_dir
+    ///
+    /// Add a directory to the file system.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The path of the directory.
+    fn add_dir(&mut self, path: &str, name: &str) {
+        let full_path = if path == "/" {
+            format!("/{}", name)
+        } else {
+            format!("{}/{}", path, name)
+        };
+        self.directories.insert(
+            full_path.clone(),
+            Directory {
+                size: 0,
+                parent: Some(path.to_string()),
+            },
+        );
+    }
+}
+
+// Functions  =========================================================================== Functions
+impl Solution for Day07 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let mut fs = FileSystem::new();
+        let mut current_path = "/".to_string();
+
+        parse::lines_with(input, |line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts[..] {
+                ["$", "cd", "/"] => current_path = "/".to_string(),
+                ["$", "cd", ".."] => {
+                    let parent = fs
+                        .directories
+                        .get(&current_path)
+                        .and_then(|dir| dir.parent.clone());
+                    if let Some(parent) = parent {
+                        current_path = parent;
+                    }
+                }
+                ["$", "cd", dir] => {
+                    current_path = if current_path == "/" {
+                        format!("/{}", dir)
+                    } else {
+                        format!("{}/{}", current_path, dir)
+                    };
+                }
+                ["$", "ls"] => {}
+                ["dir", name] => fs.add_dir(&current_path, name),
+                [size, _] => fs.add_file(&current_path, parse::number(line, size)?),
+                _ => return Err(AocError::new("expected a command, a directory or a file")),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(fs)
+    }
+
+    fn part_one(fs: &Self::Input) -> Answer {
+        // Sum of total sizes of directories with size at most 100000
+        fs.directories
+            .values()
+            .filter(|dir| dir.size <= 100000)
+            .map(|dir| dir.size)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(fs: &Self::Input) -> Answer {
+        const TOTAL_DISK_SPACE: usize = 70000000;
+        const REQUIRED_UNUSED_SPACE: usize = 30000000;
+
+        let used_space = fs.directories["/"].size;
+        let unused_space = TOTAL_DISK_SPACE - used_space;
+        let space_to_free = REQUIRED_UNUSED_SPACE - unused_space;
+
+        // Size of the smallest directory that, if deleted, would free up enough space
+        fs.directories
+            .values()
+            .filter(|dir| dir.size >= space_to_free)
+            .map(|dir| dir.size)
+            .min()
+            .into()
+    }
+}
@@ -0,0 +1,138 @@
+//!
+//! # day_08.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::{Grid, DIRECTIONS_4};
+use crate::point::Point;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 8, Day08);
+
+pub struct Forest {
+    trees: Grid<u8>,
+}
+
+impl Forest {
+    ///
+    /// # new
+    ///
+    /// Create a new forest from a &str.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` - The input string.
+    ///
+    /// ## Returns
+    ///
+    /// * A new Forest, or where the input isn't a rectangle of digits.
+    fn new(input: &str) -> Result<Self, AocError> {
+        let trees = Grid::parse(input, |height, _| {
+            height
+                .to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| AocError::new("expected a height"))
+        })?;
+
+        Ok(Forest { trees })
+    }
+
+    ///
+    /// # is_visible
+    ///
+    /// A tree is visible if all of the other trees between it and an edge of the grid are
+    /// shorter than it, in at least one direction.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tree` - The position of the tree.
+    ///
+    /// ## Returns
+    ///
+    /// * True if the tree is visible, false otherwise.
+    fn is_visible(&self, tree: Point<usize>) -> bool {
+        let height = self.trees[tree];
+
+        DIRECTIONS_4.iter().any(|&direction| {
+            self.trees
+                .ray(tree, direction)
+                .all(|other| self.trees[other] < height)
+        })
+    }
+
+    ///
+    /// # viewing_distance
+    ///
+    /// Calculate the viewing distance of a tree in a given direction.
+    ///
+    /// ## Arguments
+    ///
+    /// * `tree` - The position of the tree.
+    /// * `direction` - The direction to calculate the viewing distance.
+    ///
+    /// ## Returns
+    ///
+    /// * The viewing distance.
+    fn viewing_distance(&self, tree: Point<usize>, direction: Point<isize>) -> usize {
+        let height = self.trees[tree];
+        let mut distance = 0;
+
+        for other in self.trees.ray(tree, direction) {
+            distance += 1;
+            if self.trees[other] >= height {
+                break;
+            }
+        }
+
+        distance
+    }
+
+    fn scenic_score(&self, tree: Point<usize>) -> usize {
+        DIRECTIONS_4
+            .iter()
+            .map(|&direction| self.viewing_distance(tree, direction))
+            .product()
+    }
+
+    ///
+    /// # count_visible_trees
+    ///
+    /// ## Returns
+    ///
+    /// * The number of trees visible from outside the grid.
+    fn count_visible_trees(&self) -> usize {
+        self.trees
+            .points()
+            .filter(|&tree| self.is_visible(tree))
+            .count()
+    }
+
+    fn highest_scenic_score(&self) -> usize {
+        self.trees
+            .points()
+            .map(|tree| self.scenic_score(tree))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+// Functions  =========================================================================== Functions
+impl Solution for Day08 {
+    type Input = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Forest::new(input)
+    }
+
+    fn part_one(forest: &Self::Input) -> Answer {
+        forest.count_visible_trees().into()
+    }
+
+    fn part_two(forest: &Self::Input) -> Answer {
+        forest.highest_scenic_score().into()
+    }
+}
@@ -0,0 +1,149 @@
+//!
+//! # day_09.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::point::Point;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 9, Day09);
+
+type Position = Point<i32>;
+
+///
+/// # Rope
+///
+/// A rope pulled by the motions of its head, one position per step.
+struct Rope<'a> {
+    motions: &'a [(Position, usize)],
+    /// The motion being done, and how far the head already went.
+    motion: usize,
+    moved: usize,
+    knots: Vec<Position>,
+    /// Every position visited by the tail (last knot) of the rope.
+    tail_positions: HashSet<Position>,
+}
+
+impl<'a> Rope<'a> {
+    fn new(motions: &'a [(Position, usize)], knot_count: usize) -> Self {
+        let mut rope = Rope {
+            motions,
+            motion: 0,
+            moved: 0,
+            // the whole rope starts at the origin
+            knots: vec![Position::origin(); knot_count],
+            tail_positions: HashSet::from([Position::origin()]),
+        };
+        rope.skip_done_motions();
+
+        rope
+    }
+
+    ///
+    /// # skip_done_motions
+    ///
+    /// Go to the next motion the head still has to do, skipping those of 0 steps.
+    fn skip_done_motions(&mut self) {
+        while self
+            .motions
+            .get(self.motion)
+            .is_some_and(|&(_, steps)| self.moved == steps)
+        {
+            self.motion += 1;
+            self.moved = 0;
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    fn step(&mut self) {
+        // move the head
+        let (direction, _) = self.motions[self.motion];
+        self.knots[0] += direction;
+        self.moved += 1;
+
+        // move each knot to follow the previous one
+        for i in 1..self.knots.len() {
+            let previous = self.knots[i - 1];
+            follow(&mut self.knots[i], previous);
+        }
+
+        self.tail_positions.insert(self.knots[self.knots.len() - 1]);
+        self.skip_done_motions();
+    }
+
+    fn is_done(&self) -> bool {
+        self.motion >= self.motions.len()
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # follow
+///
+/// Move a knot to follow another knot, when they aren't touching anymore.
+///
+/// ## Arguments
+///
+/// * `knot` - The knot to move.
+/// * `other` - The knot to follow.
+fn follow(knot: &mut Position, other: Position) {
+    if knot.chebyshev_distance(&other) > 1 {
+        let offset = other - *knot;
+        *knot += Position::new(offset.x.signum(), offset.y.signum());
+    }
+}
+
+///
+/// # simulate_rope
+///
+/// ## Arguments
+///
+/// * `motions` - The direction and number of steps of each head motion.
+/// * `knot_count` - The number of knots in the rope.
+///
+/// ## Returns
+///
+/// The number of unique positions visited by the tail of the rope.
+fn simulate_rope(motions: &[(Position, usize)], knot_count: usize) -> usize {
+    let mut rope = Rope::new(motions, knot_count);
+    simulation::run_until_done(&mut rope);
+
+    rope.tail_positions.len()
+}
+
+impl Solution for Day09 {
+    type Input = Vec<(Position, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines_with(input, |line| {
+            let (direction, steps) = line
+                .split_once(' ')
+                .ok_or_else(|| AocError::new("expected a direction and a number of steps"))?;
+            let direction = match direction {
+                "U" => Position::new(0, 1),
+                "D" => Position::new(0, -1),
+                "L" => Position::new(-1, 0),
+                "R" => Position::new(1, 0),
+                _ => return Err(AocError::new("expected U, D, L or R").at_column(1)),
+            };
+
+            Ok((direction, parse::number(line, steps)?))
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        // The number of positions the tail of the rope visited at least once.
+        simulate_rope(input, 2).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        simulate_rope(input, 10).into()
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! # day_10.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::ocr;
+use crate::parse::{self, FromInput};
+use crate::point::Point;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+aoc_day!(2022, 10, Day10);
+
+#[derive(Debug, Clone, FromInput)]
+pub enum Instruction {
+    #[input("noop")]
+    Noop,
+    #[input("addx {0}")]
+    Addx(i32),
+}
+
+///
+/// # Cpu
+///
+/// The CPU running the program, one clock cycle per step, with the signal strengths it sums and
+/// the CRT it draws on along the way.
+struct Cpu<'a> {
+    program: &'a [Instruction],
+    /// The instruction being executed.
+    pc: usize,
+    /// The cycles already spent on the instruction being executed.
+    progress: u8,
+    x: i32,
+    /// The number of cycles completed.
+    cycle: i32,
+    signal_strength_sum: i32,
+    crt: Grid<bool>,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(program: &'a [Instruction]) -> Self {
+        Cpu {
+            program,
+            pc: 0,
+            progress: 0,
+            x: 1,
+            cycle: 0,
+            signal_strength_sum: 0,
+            crt: Grid::new(CRT_WIDTH, CRT_HEIGHT, false),
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    fn step(&mut self) {
+        // during the cycle
+        check_signal_strength(self.cycle + 1, self.x, &mut self.signal_strength_sum);
+        draw_pixel(&mut self.crt, self.cycle, self.x);
+
+        // after the cycle
+        self.cycle += 1;
+        self.progress += 1;
+        match self.program[self.pc] {
+            Instruction::Noop => {}
+            Instruction::Addx(v) if self.progress == 2 => self.x += v,
+            Instruction::Addx(_) => return,
+        }
+        self.pc += 1;
+        self.progress = 0;
+    }
+
+    fn is_done(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # check_signal_strength
+///
+/// Check the signal strength for a given cycle.
+///
+/// ## Arguments
+///
+/// * `cycle` - The current cycle.
+/// * `x` - The current x value.
+/// * `sum` - The sum of the signal strengths.
+fn check_signal_strength(cycle: i32, x: i32, sum: &mut i32) {
+    if (cycle - 20) % 40 == 0 && cycle <= 220 {
+        *sum += cycle * x;
+    }
+}
+
+/// # draw_pixel
+///
+/// Draw a pixel on the CRT screen.
+/// The pixel is drawn at the current cycle and x value.
+/// The pixel is lit when the sprite covers it.
+/// The CRT screen is a 6x40 grid: the cycles after the last row draw nothing.
+///
+/// ## Arguments
+///
+/// * `crt` - The CRT screen.
+/// * `cycle` - The current cycle.
+/// * `x` - The current x value.
+fn draw_pixel(crt: &mut Grid<bool>, cycle: i32, x: i32) {
+    let row = (cycle / CRT_WIDTH as i32) as usize;
+    let col = (cycle % CRT_WIDTH as i32) as usize;
+
+    if (x - 1..=x + 1).contains(&(col as i32)) {
+        if let Some(pixel) = crt.get_mut(Point::new(col, row)) {
+            *pixel = true;
+        }
+    }
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input)
+    }
+
+    fn part_one(instructions: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new(instructions);
+        simulation::run_until_done(&mut cpu);
+
+        cpu.signal_strength_sum.into()
+    }
+
+    fn part_two(instructions: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new(instructions);
+        simulation::run_until_done(&mut cpu);
+        let crt = cpu.crt;
+
+        // The answer is the letters drawn on the CRT. The example draws no letters, so its
+        // answer is the CRT output, one line per row.
+        match ocr::recognize(&crt) {
+            Ok(letters) => letters.into(),
+            Err(_) => crt
+                .map(|&lit| if lit { '#' } else { '.' })
+                .to_string()
+                .trim_end()
+                .into(),
+        }
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_longer_than_the_crt() {
+        let program = vec![Instruction::Noop; CRT_WIDTH * CRT_HEIGHT + 1];
+        let mut cpu = Cpu::new(&program);
+
+        assert_eq!(simulation::run_until_done(&mut cpu), program.len());
+        assert_eq!(cpu.signal_strength_sum, 20 + 60 + 100 + 140 + 180 + 220);
+    }
+}
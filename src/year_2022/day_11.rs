@@ -0,0 +1,343 @@
+//!
+//! # day_11.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::scan;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 11, Day11);
+
+///
+/// # Expr
+///
+/// The operation of a monkey, computing the new worry level of an item from the `old` one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Old,
+    Literal(u64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    ///
+    /// # eval
+    ///
+    /// ## Arguments
+    ///
+    /// * `old` - The worry level before the operation
+    /// * `modulus` - The modulus to compute the operation under, if any
+    ///
+    /// ## Returns
+    ///
+    /// * `u64` - The worry level after the operation, reduced modulo `modulus`
+    fn eval(&self, old: u64, modulus: Option<u64>) -> u64 {
+        let value = match self {
+            Expr::Old => old,
+            Expr::Literal(value) => *value,
+            Expr::Add(left, right) => left.eval(old, modulus) + right.eval(old, modulus),
+            Expr::Mul(left, right) => left.eval(old, modulus) * right.eval(old, modulus),
+        };
+
+        modulus.map_or(value, |modulus| value % modulus)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The nested operations are parenthesized, the operators having different precedences
+        let operand = |f: &mut fmt::Formatter<'_>, expr: &Expr| match expr {
+            Expr::Add(..) | Expr::Mul(..) => write!(f, "({})", expr),
+            _ => write!(f, "{}", expr),
+        };
+
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Literal(value) => write!(f, "{}", value),
+            Expr::Add(left, right) => {
+                operand(f, left)?;
+                write!(f, " + ")?;
+                operand(f, right)
+            }
+            Expr::Mul(left, right) => {
+                operand(f, left)?;
+                write!(f, " * ")?;
+                operand(f, right)
+            }
+        }
+    }
+}
+
+///
+/// # Monkey
+///
+/// A monkey, the items it holds and how it decides where to throw them.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Expr,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+    inspections: u64,
+}
+
+impl FromStr for Monkey {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, items, operator, operand, test, if_true, if_false) = scan!(
+            s.trim_end(),
+            "Monkey {}:
+  Starting items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}"
+            => usize, &str, char, &str, u64, usize, usize
+        )?;
+
+        let items = parse::list(s, items, ", ")?;
+        let operand = match operand {
+            "old" => Expr::Old,
+            _ => Expr::Literal(parse::field(s, operand)?),
+        };
+        let operation = match operator {
+            '+' => Expr::Add(Box::new(Expr::Old), Box::new(operand)),
+            '*' => Expr::Mul(Box::new(Expr::Old), Box::new(operand)),
+            _ => {
+                let line = s.lines().nth(2).unwrap_or_default();
+                return Err(AocError::new("expected `+` or `*`")
+                    .at_column(line.find(operator).unwrap_or_default() + 1)
+                    .at_line(3, line));
+            }
+        };
+
+        if test == 0 {
+            let line = s.lines().nth(3).unwrap_or_default();
+            return Err(AocError::new("can't test the divisibility by 0").at_line(4, line));
+        }
+
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+            if_true,
+            if_false,
+            inspections: 0,
+        })
+    }
+}
+
+///
+/// # parse_monkeys
+///
+/// Parse the monkeys, separated by blank lines.
+///
+/// ## Arguments
+///
+/// * `input` - The input string.
+///
+/// ## Returns
+///
+/// * The monkeys, or where the input is malformed.
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let monkeys: Vec<Monkey> = parse::blocks(input.trim_end(), str::parse)?;
+    if monkeys.len() < 2 {
+        return Err(AocError::new(
+            "the monkey business needs at least 2 monkeys",
+        ));
+    }
+
+    for (index, monkey) in monkeys.iter().enumerate() {
+        if let Some(target) = [monkey.if_true, monkey.if_false]
+            .into_iter()
+            .find(|&target| target >= monkeys.len())
+        {
+            return Err(AocError::new(format!(
+                "monkey {} throws to monkey {}, which doesn't exist",
+                index, target
+            )));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+///
+/// # Relief
+///
+/// How the worry level of an item is kept in check after each inspection.
+enum Relief {
+    /// Part 1: the worry level is divided by 3.
+    Divide,
+    /// Part 2: the worry level is kept modulo the product of the tests, which keeps every test.
+    Modulo(u64),
+}
+
+///
+/// # Troop
+///
+/// The monkeys throwing the items, one round of throws per step.
+struct Troop {
+    monkeys: Vec<Monkey>,
+    relief: Relief,
+}
+
+impl Troop {
+    fn new(monkeys: Vec<Monkey>, relief: Relief) -> Self {
+        Troop { monkeys, relief }
+    }
+}
+
+impl Simulation for Troop {
+    fn step(&mut self) {
+        for i in 0..self.monkeys.len() {
+            let mut throws = Vec::new();
+
+            // separated scope to avoid borrowing issues
+            {
+                let monkey = &mut self.monkeys[i];
+
+                while let Some(item) = monkey.items.pop() {
+                    monkey.inspections += 1;
+
+                    let worry = match self.relief {
+                        Relief::Divide => monkey.operation.eval(item, None) / 3,
+                        Relief::Modulo(modulus) => monkey.operation.eval(item, Some(modulus)),
+                    };
+
+                    let target = if worry.is_multiple_of(monkey.test) {
+                        monkey.if_true
+                    } else {
+                        monkey.if_false
+                    };
+
+                    throws.push((target, worry));
+                }
+            }
+
+            for (target, item) in throws {
+                self.monkeys[target].items.push(item);
+            }
+        }
+    }
+}
+
+// Functions  =========================================================================== Functions
+///
+/// # monkey_business
+///
+/// Compute the level of monkey business: the product of the inspection counts of the two most
+/// active monkeys.
+///
+/// ## Arguments
+///
+/// * `monkeys` - The monkeys, after the simulation.
+///
+/// ## Returns
+///
+/// * `u64` - The level of monkey business.
+fn monkey_business(monkeys: &[Monkey]) -> u64 {
+    let mut inspection_counts: Vec<u64> = monkeys.iter().map(|m| m.inspections).collect();
+    inspection_counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    inspection_counts[0] * inspection_counts[1]
+}
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_monkeys(input)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Answer {
+        let mut troop = Troop::new(monkeys.clone(), Relief::Divide);
+        simulation::run(&mut troop, 20);
+
+        monkey_business(&troop.monkeys).into()
+    }
+
+    fn part_two(monkeys: &Self::Input) -> Answer {
+        let common_modulus = monkeys.iter().map(|m| m.test).product();
+        let mut troop = Troop::new(monkeys.clone(), Relief::Modulo(common_modulus));
+        simulation::run(&mut troop, 10000);
+
+        monkey_business(&troop.monkeys).into()
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1";
+
+    #[test]
+    fn test_monkey_from_str() {
+        let monkey: Monkey = MONKEY.parse().unwrap();
+
+        assert_eq!(monkey.items, vec![79, 98]);
+        assert_eq!(monkey.operation.eval(2, None), 38);
+        assert_eq!(monkey.operation.to_string(), "old * 19");
+        assert_eq!((monkey.test, monkey.if_true, monkey.if_false), (23, 1, 1));
+    }
+
+    #[test]
+    fn test_expr() {
+        let square = Expr::Mul(Box::new(Expr::Old), Box::new(Expr::Old));
+        let expr = Expr::Add(Box::new(square), Box::new(Expr::Literal(3)));
+
+        assert_eq!(expr.to_string(), "(old * old) + 3");
+        assert_eq!(expr.eval(7, None), 52);
+        assert_eq!(expr.eval(7, Some(10)), 2);
+    }
+
+    #[test]
+    fn test_parse_errors_positions() {
+        let input = format!("{}\n\n{}", MONKEY, MONKEY.replace("98", "9x"));
+        let error = parse_monkeys(&input).err().unwrap();
+        assert_eq!(error.message(), "invalid number `9x`");
+        assert_eq!((error.line(), error.column()), (Some(9), Some(23)));
+
+        let error = MONKEY
+            .replace("If false", "If flase")
+            .parse::<Monkey>()
+            .err()
+            .unwrap();
+        assert_eq!(error.message(), "expected `If false: throw to monkey`");
+        assert_eq!((error.line(), error.column()), (Some(6), Some(9)));
+
+        let error = MONKEY
+            .replace("* 19", "/ 19")
+            .parse::<Monkey>()
+            .err()
+            .unwrap();
+        assert_eq!(error.message(), "expected `+` or `*`");
+        assert_eq!((error.line(), error.column()), (Some(3), Some(24)));
+
+        let error = parse_monkeys(&MONKEY.replace("monkey 1", "monkey 0"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.message(),
+            "the monkey business needs at least 2 monkeys"
+        );
+    }
+}
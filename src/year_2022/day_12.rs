@@ -0,0 +1,205 @@
+//!
+//! # day_12.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::image::Image;
+use crate::point::Point;
+use crate::search::{self, Found};
+use crate::solution::{Answer, Solution};
+use crate::visualize::Visualize;
+
+use std::str::FromStr;
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 12, Day12);
+
+type MyPoint = Point<usize>;
+
+pub struct HeightMap {
+    map: Grid<u8>,
+    start: MyPoint,
+    end: MyPoint,
+}
+
+impl FromStr for HeightMap {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let markers: Grid<char> = Grid::parse(s, |c, _| match c {
+            'S' | 'E' | 'a'..='z' => Ok(c),
+            _ => Err(AocError::new("expected a height from a to z")),
+        })?;
+
+        Ok(HeightMap {
+            map: markers.map(|&c| match c {
+                'S' => b'a',
+                'E' => b'z',
+                _ => c as u8,
+            }),
+            start: markers
+                .find(&'S')
+                .ok_or_else(|| AocError::new("missing the start `S`"))?,
+            end: markers
+                .find(&'E')
+                .ok_or_else(|| AocError::new("missing the end `E`"))?,
+        })
+    }
+}
+
+impl HeightMap {
+    ///
+    /// # is_valid_move
+    ///
+    /// Check if the move is valid.
+    /// A move is valid if the height difference is at most 1, both points being in the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The starting point.
+    /// * `to` - The destination point.
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the move is valid, false otherwise.
+    fn is_valid_move(&self, from: MyPoint, to: MyPoint) -> bool {
+        self.map[to] <= self.map[from] + 1 // Check if the height difference is at most 1
+    }
+
+    ///
+    /// # shortest_path_from
+    ///
+    /// Find the shortest path from any of the starting points to the end point.
+    /// It uses a breadth-first search algorithm to find the shortest path.
+    ///
+    /// ## Arguments
+    ///
+    /// * `starts` - The starting points.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Found<MyPoint, usize>>` - The end point and the number of steps required to
+    ///   reach it.
+    fn shortest_path_from(
+        &self,
+        starts: impl IntoIterator<Item = MyPoint>,
+    ) -> Option<Found<MyPoint, usize>> {
+        search::bfs_multi(
+            starts,
+            |&current| {
+                self.map
+                    .neighbors4(current)
+                    .filter(move |&next| self.is_valid_move(current, next))
+            },
+            |&point| point == self.end,
+        )
+    }
+
+    ///
+    /// # find_shortest_path
+    ///
+    /// Find the shortest path from the start to the end point.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<usize>` - The number of steps required to reach the end point.
+    fn find_shortest_path(&self) -> Option<usize> {
+        self.shortest_path_from([self.start])
+            .map(|found| found.cost)
+    }
+
+    ///
+    /// # find_shortest_path_from_any_a
+    ///
+    /// Find the shortest path from any 'a' elevation point to the end point.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<usize>` - The number of steps required to reach the end point.
+    fn find_shortest_path_from_any_a(&self) -> Option<usize> {
+        // Instead of starting from the start point, we start from any 'a' elevation point
+        self.shortest_path_from(
+            self.map
+                .iter()
+                .filter(|(_, &height)| height == b'a')
+                .map(|(point, _)| point),
+        )
+        .map(|found| found.cost)
+    }
+}
+
+///
+/// # ClimbAnimation
+///
+/// The shortest path from the start to the end, one step per frame.
+pub struct ClimbAnimation {
+    map: Grid<u8>,
+    path: Vec<MyPoint>,
+    /// The number of steps already taken
+    taken: usize,
+}
+
+impl Visualize for ClimbAnimation {
+    fn step(&mut self) -> bool {
+        if self.taken + 1 >= self.path.len() {
+            return false;
+        }
+        self.taken += 1;
+        true
+    }
+
+    fn render(&self) -> Grid<char> {
+        let mut frame = self.map.map(|&height| height as char);
+        for &point in &self.path[..self.taken] {
+            frame[point] = '*';
+        }
+        frame[self.path[self.taken]] = '@';
+        frame
+    }
+
+    fn focus(&self) -> Option<MyPoint> {
+        Some(self.path[self.taken])
+    }
+
+    /// The heights on the heightmap ramp, with the path in red and the climber in white.
+    fn image(&self) -> Image {
+        let mut image = Image::heightmap(&self.map, |&height| height as f64);
+        for &point in &self.path[..self.taken] {
+            image.set_pixel(point.x, point.y, [220, 50, 50]);
+        }
+        let climber = self.path[self.taken];
+        image.set_pixel(climber.x, climber.y, [255, 255, 255]);
+        image
+    }
+}
+// Functions  =========================================================================== Functions
+impl Solution for Day12 {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part_one(height_map: &Self::Input) -> Answer {
+        // Fewest steps required
+        height_map.find_shortest_path().into()
+    }
+
+    fn part_two(height_map: &Self::Input) -> Answer {
+        // Fewest steps required from any 'a' elevation
+        height_map.find_shortest_path_from_any_a().into()
+    }
+
+    fn visualize(height_map: &Self::Input) -> Option<Box<dyn Visualize>> {
+        let found = height_map.shortest_path_from([height_map.start])?;
+
+        Some(Box::new(ClimbAnimation {
+            map: height_map.map.clone(),
+            path: found.path(),
+            taken: 0,
+        }))
+    }
+}
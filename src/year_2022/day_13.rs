@@ -1,13 +1,17 @@
-///
-/// # day_13.rs
-/// Code for the day 01 of the Advent of Code challenge year 2022
-///
+//!
+//! # day_13.rs
+//! Code for the day 01 of the Advent of Code challenge year 2022
+//!
 // Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
 use std::cmp::Ordering;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../../data/year_2022/inputs/day_13.txt");
+aoc_day!(2022, 13, Day13);
 
 ///
 /// # PacketData
@@ -20,14 +24,10 @@ enum PacketData {
 
 /// Wrapper struct for PacketData
 #[derive(Debug, Clone)]
-struct Packet(PacketData);
-
-/// Custom error type for packet parsing
-#[derive(Debug)]
-struct ParsePacketError;
+pub struct Packet(PacketData);
 
 impl FromStr for Packet {
-    type Err = ParsePacketError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         ///
@@ -37,19 +37,21 @@ impl FromStr for Packet {
         /// This function is recursive to handle nested lists
         ///
         /// # Arguments
+        /// * `s` - The whole packet, for error messages
         /// * `chars` - A mutable reference to a peekable iterator of characters
         ///
         /// # Returns
-        /// * `Result<Vec<PacketData>, ParsePacketError>` - The parsed list or an error
+        /// * `Result<Vec<PacketData>, AocError>` - The parsed list or an error
         fn parse_list(
-            chars: &mut std::iter::Peekable<std::str::Chars>,
-        ) -> Result<Vec<PacketData>, ParsePacketError> {
+            s: &str,
+            chars: &mut std::iter::Peekable<std::str::CharIndices>,
+        ) -> Result<Vec<PacketData>, AocError> {
             let mut list = Vec::new();
-            while let Some(&c) = chars.peek() {
+            while let Some(&(i, c)) = chars.peek() {
                 match c {
                     '[' => {
                         chars.next();
-                        list.push(PacketData::List(parse_list(chars)?));
+                        list.push(PacketData::List(parse_list(s, chars)?));
                     }
                     ']' => {
                         chars.next();
@@ -60,31 +62,35 @@ impl FromStr for Packet {
                     }
                     _ => {
                         // Parse integer
-                        let mut num = String::new();
-                        while let Some(&c) = chars.peek() {
+                        let mut end = i;
+                        while let Some(&(j, c)) = chars.peek() {
                             if c.is_ascii_digit() {
-                                num.push(chars.next().unwrap());
+                                chars.next();
+                                end = j + 1;
                             } else {
                                 break;
                             }
                         }
-                        list.push(PacketData::Integer(
-                            num.parse().map_err(|_| ParsePacketError)?,
-                        ));
+                        if end == i {
+                            return Err(
+                                AocError::new(format!("unexpected `{}`", c)).at_column(i + 1)
+                            );
+                        }
+                        list.push(PacketData::Integer(parse::number(s, &s[i..end])?));
                     }
                 }
             }
-            Err(ParsePacketError)
+            Err(AocError::new("unclosed list").at_column(s.len() + 1))
         }
 
-        let mut chars = s.chars().peekable();
-        if chars.next() != Some('[') {
-            return Err(ParsePacketError);
+        let mut chars = s.char_indices().peekable();
+        if chars.next().map(|(_, c)| c) != Some('[') {
+            return Err(AocError::new("a packet is a list starting with `[`").at_column(1));
         }
 
-        let data = PacketData::List(parse_list(&mut chars)?);
-        if chars.next().is_some() {
-            return Err(ParsePacketError);
+        let data = PacketData::List(parse_list(s, &mut chars)?);
+        if let Some((i, _)) = chars.next() {
+            return Err(AocError::new("unexpected data after the packet").at_column(i + 1));
         }
 
         Ok(Packet(data))
@@ -103,7 +109,7 @@ impl Eq for Packet {}
 
 impl PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.compare(other))
+        Some(self.cmp(other))
     }
 }
 
@@ -164,55 +170,59 @@ impl Packet {
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
-    // Parse input into pairs of packets
-    let pairs: Vec<_> = INPUT
-        .split("\n\n")
-        .map(|pair| {
-            let mut lines = pair.lines();
-            (
-                lines.next().unwrap().parse::<Packet>().unwrap(),
-                lines.next().unwrap().parse::<Packet>().unwrap(),
-            )
-        })
-        .collect();
-
-    // Sum indices of pairs in the right order
-    // We add 1 to the index because the puzzle uses 1-based indexing
-    let sum: usize = pairs
-        .iter()
-        .enumerate()
-        .filter(|(_, (left, right))| left.compare(right) == Ordering::Less)
-        .map(|(i, _)| i + 1)
-        .sum();
-
-    println!("Day 13 - Part 1: {}", sum);
-}
+impl Solution for Day13 {
+    // Every packet of the input, in order. Pairs are consecutive packets.
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let packets = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(|error: AocError| error.at_line(index + 1, line))
+            })
+            .collect::<Result<Vec<Packet>, AocError>>()?;
+
+        if packets.len() % 2 != 0 {
+            return Err(AocError::new("the last packet doesn't have a pair"));
+        }
 
-pub fn response_part_2() {
-    let mut packets: Vec<Packet> = INPUT
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.parse().unwrap())
-        .collect();
+        Ok(packets)
+    }
 
-    // Add divider packets
-    // These are special packets that we need to track in the sorted list
-    let divider1: Packet = "[[2]]".parse().unwrap();
-    let divider2: Packet = "[[6]]".parse().unwrap();
-    packets.push(divider1.clone());
-    packets.push(divider2.clone());
+    fn part_one(packets: &Self::Input) -> Answer {
+        // Sum indices of pairs in the right order
+        // We add 1 to the index because the puzzle uses 1-based indexing
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0].compare(&pair[1]) == Ordering::Less)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>()
+            .into()
+    }
 
-    // Sort packets
-    packets.sort();
+    fn part_two(packets: &Self::Input) -> Answer {
+        let mut packets = packets.clone();
 
-    // Find indices of divider packets
-    // We add 1 to convert from 0-based to 1-based indexing
-    let index1 = packets.iter().position(|p| p == &divider1).unwrap() + 1;
-    let index2 = packets.iter().position(|p| p == &divider2).unwrap() + 1;
+        // Add divider packets
+        // These are special packets that we need to track in the sorted list
+        let divider1: Packet = "[[2]]".parse().unwrap();
+        let divider2: Packet = "[[6]]".parse().unwrap();
+        packets.push(divider1.clone());
+        packets.push(divider2.clone());
 
-    // Calculate decoder key
-    let decoder_key = index1 * index2;
+        // Sort packets
+        packets.sort();
 
-    println!("Day 13 - Part 2: {}", decoder_key);
+        // Find indices of divider packets
+        // We add 1 to convert from 0-based to 1-based indexing
+        let index1 = packets.iter().position(|p| p == &divider1).unwrap() + 1;
+        let index2 = packets.iter().position(|p| p == &divider2).unwrap() + 1;
+
+        // Calculate decoder key
+        (index1 * index2).into()
+    }
 }
@@ -0,0 +1,421 @@
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::parse;
+use crate::point::Point;
+use crate::simulation::{self, Simulation};
+use crate::solution::{Answer, Solution};
+use crate::visualize::Visualize;
+
+use std::{fmt, str::FromStr};
+
+// Variables  =========================================================================== Variables
+const SAND_SOURCE: MyPoint = MyPoint { x: 500, y: 0 };
+
+/// The moves tried by a falling unit of sand, in order: down, down-left and down-right.
+const FALLS: [MyPoint; 3] = [
+    MyPoint { x: 0, y: 1 },
+    MyPoint { x: -1, y: 1 },
+    MyPoint { x: 1, y: 1 },
+];
+
+aoc_day!(2022, 14, Day14);
+
+type MyPoint = Point<i32>;
+
+///
+/// # Tile
+///
+/// What fills a position of the cave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+///
+/// # Cave
+///
+/// The slice of the cave the sand can reach, above the floor.
+/// The first column of the grid is at `x = min_x`.
+#[derive(Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    min_x: i32,
+    abyss_y: i32,
+    floor_y: i32,
+}
+
+impl FromStr for Cave {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let paths = parse::lines_with(s, |line| {
+            line.split(" -> ")
+                .map(|point| point.parse().map_err(|e: AocError| e.within(line, point)))
+                .collect::<Result<Vec<MyPoint>, AocError>>()
+        })?;
+
+        let mut rocks = Vec::new();
+        for point in paths {
+            for window in point.windows(2) {
+                let [start, end] = window else { continue };
+                for x in start.x.min(end.x)..=start.x.max(end.x) {
+                    for y in start.y.min(end.y)..=start.y.max(end.y) {
+                        rocks.push(MyPoint { x, y });
+                    }
+                }
+            }
+        }
+
+        if let Some(rock) = rocks.iter().find(|rock| rock.y < 0) {
+            return Err(AocError::new(format!(
+                "the rock at {} is above the sand source",
+                rock
+            )));
+        }
+
+        let abyss_y = rocks
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .ok_or_else(|| AocError::new("there is no rock in the cave"))?
+            + 1;
+        let floor_y = abyss_y + 1; // The floor is one unit below the abyss
+
+        // The sand moves at most one column per row, so it never goes further than the floor depth
+        let min_x = rocks
+            .iter()
+            .map(|p| p.x)
+            .chain([SAND_SOURCE.x - floor_y])
+            .min()
+            .unwrap_or_default()
+            - 1;
+        let max_x = rocks
+            .iter()
+            .map(|p| p.x)
+            .chain([SAND_SOURCE.x + floor_y])
+            .max()
+            .unwrap_or_default()
+            + 1;
+
+        let mut cave = Cave {
+            tiles: Grid::new((max_x - min_x + 1) as usize, floor_y as usize, Tile::Air),
+            min_x,
+            abyss_y,
+            floor_y,
+        };
+        for rock in rocks {
+            cave.set(rock, Tile::Rock);
+        }
+
+        Ok(cave)
+    }
+}
+
+impl Cave {
+    /// # simulate_sand
+    ///
+    /// Simulate the fall of a single unit of sand
+    ///
+    /// ## Arguments
+    ///
+    /// * `part2` - Boolean flag to indicate if we're solving part 2 of the puzzle
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the sand came to rest, false if it fell into the abyss or source is blocked
+    fn simulate_sand(&mut self, part2: bool) -> bool {
+        let mut sand = SAND_SOURCE;
+        if self.is_occupied(sand) {
+            return false; // Source is blocked
+        }
+        loop {
+            if sand.y + 1 == self.floor_y && !part2 {
+                return false; // Sand falls into the abyss in part 1
+            }
+            if let Some(next) = self.fall(sand) {
+                sand = next;
+            } else {
+                self.set(sand, Tile::Sand);
+                return true;
+            }
+        }
+    }
+
+    ///
+    /// # fall
+    ///
+    /// ## Arguments
+    ///
+    /// * `sand` - The position of a unit of sand
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<MyPoint>` - Where it falls next: down, down-left, then down-right,
+    ///   or `None` if it comes to rest
+    fn fall(&self, sand: MyPoint) -> Option<MyPoint> {
+        FALLS
+            .iter()
+            .map(|&fall| sand + fall)
+            .find(|&next| !self.is_occupied(next))
+    }
+
+    ///  # is_occupied
+    ///
+    /// Check if a given point is occupied by rock, sand, or floor
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The point to check
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the point is occupied, false otherwise
+    fn is_occupied(&self, p: MyPoint) -> bool {
+        p.y == self.floor_y || self.tile(p) != Tile::Air
+    }
+
+    ///
+    /// # cell
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - A point of the cave
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<Point<usize>>` - Its position in the grid, `None` if it is outside of it
+    fn cell(&self, p: MyPoint) -> Option<Point<usize>> {
+        let cell = Point::new(
+            usize::try_from(p.x - self.min_x).ok()?,
+            usize::try_from(p.y).ok()?,
+        );
+
+        self.tiles.contains(cell).then_some(cell)
+    }
+
+    ///
+    /// # tile
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - A point of the cave
+    ///
+    /// ## Returns
+    ///
+    /// * `Tile` - What fills the point, air outside of the grid
+    fn tile(&self, p: MyPoint) -> Tile {
+        self.cell(p).map_or(Tile::Air, |cell| self.tiles[cell])
+    }
+
+    ///
+    /// # set
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - A point of the grid
+    /// * `tile` - What fills it now
+    fn set(&mut self, p: MyPoint, tile: Tile) {
+        let cell = self.cell(p).expect("the grid covers every reachable point");
+        self.tiles[cell] = tile;
+    }
+}
+
+///
+/// # SandFlow
+///
+/// The units of sand poured into the cave, one per step, until one doesn't come to rest.
+struct SandFlow {
+    cave: Cave,
+    /// Whether the floor stops the sand (part 2), instead of the abyss swallowing it (part 1)
+    floor: bool,
+    settled: usize,
+    done: bool,
+}
+
+impl SandFlow {
+    fn new(cave: &Cave, floor: bool) -> Self {
+        SandFlow {
+            cave: cave.clone(),
+            floor,
+            settled: 0,
+            done: false,
+        }
+    }
+}
+
+impl Simulation for SandFlow {
+    fn step(&mut self) {
+        if self.cave.simulate_sand(self.floor) {
+            self.settled += 1;
+        } else {
+            self.done = true;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+///
+/// # SandAnimation
+///
+/// The sand of the first part falling one position per frame, until it flows into the abyss.
+pub struct SandAnimation {
+    cave: Cave,
+    /// The unit of sand currently falling
+    grain: Option<MyPoint>,
+}
+
+impl Visualize for SandAnimation {
+    fn step(&mut self) -> bool {
+        match self.grain {
+            None if self.cave.is_occupied(SAND_SOURCE) => false,
+            None => {
+                self.grain = Some(SAND_SOURCE);
+                true
+            }
+            // Below every rock, the sand falls forever
+            Some(sand) if sand.y >= self.cave.abyss_y => false,
+            Some(sand) => {
+                self.grain = self.cave.fall(sand);
+                if self.grain.is_none() {
+                    self.cave.set(sand, Tile::Sand);
+                }
+                true
+            }
+        }
+    }
+
+    fn render(&self) -> Grid<char> {
+        let height = self.cave.tiles.height() + 1; // The floor is below the grid
+        let source = self.cave.cell(SAND_SOURCE);
+        let grain = self.grain.and_then(|grain| self.cave.cell(grain));
+
+        Grid::from_fn(self.cave.tiles.width(), height, |cell| {
+            if Some(cell) == grain {
+                '~'
+            } else if Some(cell) == source {
+                '+'
+            } else if cell.y == height - 1 {
+                '#'
+            } else {
+                self.cave.tiles[cell].symbol()
+            }
+        })
+    }
+
+    fn focus(&self) -> Option<Point<usize>> {
+        self.cave.cell(self.grain.unwrap_or(SAND_SOURCE))
+    }
+}
+
+// Implement Debug for Cave to visualize the cave system
+impl fmt::Debug for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only show the columns holding rock or sand, and the source
+        let columns: Vec<i32> = self
+            .tiles
+            .iter()
+            .filter(|(_, &tile)| tile != Tile::Air)
+            .map(|(cell, _)| cell.x as i32 + self.min_x)
+            .chain([SAND_SOURCE.x])
+            .collect();
+        let min_x = columns.iter().min().unwrap_or(&SAND_SOURCE.x) - 1;
+        let max_x = columns.iter().max().unwrap_or(&SAND_SOURCE.x) + 1;
+
+        for y in 0..=self.floor_y {
+            for x in min_x..=max_x {
+                let point = MyPoint { x, y };
+                if point == SAND_SOURCE {
+                    write!(f, "+")?;
+                } else if y == self.floor_y {
+                    write!(f, "{}", Tile::Rock)?;
+                } else {
+                    write!(f, "{}", self.tile(point))?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Functions  =========================================================================== Functions
+impl Solution for Day14 {
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part_one(cave: &Self::Input) -> Answer {
+        let mut flow = SandFlow::new(cave, false);
+        simulation::run_until_done(&mut flow);
+
+        flow.settled.into()
+    }
+
+    fn part_two(cave: &Self::Input) -> Answer {
+        let mut flow = SandFlow::new(cave, true);
+        simulation::run_until_done(&mut flow);
+
+        flow.settled.into()
+    }
+
+    fn visualize(cave: &Self::Input) -> Option<Box<dyn Visualize>> {
+        Some(Box::new(SandAnimation {
+            cave: cave.clone(),
+            grain: None,
+        }))
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn test_sand_animation() {
+        let cave = Day14::parse(EXAMPLE).unwrap();
+        let Some(mut animation) = Day14::visualize(&cave) else {
+            panic!("day 14 has an animation");
+        };
+
+        let mut frames = 1;
+        while animation.step() {
+            frames += 1;
+        }
+        let frame = animation.render();
+
+        assert!(frames > 24);
+        assert_eq!(frame.values().filter(|&&c| c == 'o').count(), 24);
+        assert_eq!(
+            frame.row(frame.height() - 1).iter().collect::<String>(),
+            "#".repeat(frame.width())
+        );
+    }
+}
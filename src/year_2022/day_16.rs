@@ -0,0 +1,363 @@
+//!
+//! # day_16.rs
+//! Code for the day 16 of the Advent of Code challenge year 2022
+//!
+// Imports  ==============================================================================  Imports
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2022, 16, Day16);
+
+/// Represents a valve in the cave system
+#[derive(Debug, Clone)]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+/// The valve graph compressed to the valves worth opening, plus the starting valve "AA".
+/// The valves are interned: the valuable ones are numbered from 0, which is also their bit in a
+/// mask of opened valves, and "AA" comes last unless it is valuable itself.
+pub struct ValveSystem {
+    flow_rates: Vec<u32>,
+    /// `distances[a][b]` is the number of minutes to walk from valve `a` to valve `b`
+    distances: Vec<Vec<u32>>,
+    /// The number of valves with a non-zero flow rate, numbered from 0
+    valuable: usize,
+    start: usize,
+}
+
+impl FromStr for Valve {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, flow_rate, tunnels) =
+            scan!(s, "Valve {} has flow rate={};{}" => String, u32, &str)?;
+
+        let tunnels = tunnels
+            .strip_prefix(" tunnels lead to valves ")
+            .or_else(|| tunnels.strip_prefix(" tunnel leads to valve "))
+            .ok_or_else(|| {
+                AocError::new("expected `tunnels lead to valves YY, ZZ`").at_token(s, tunnels)
+            })?;
+
+        Ok(Valve {
+            name,
+            flow_rate,
+            tunnels: parse::list(s, tunnels, ", ")?,
+        })
+    }
+}
+
+impl ValveSystem {
+    fn new(valves: Vec<Valve>) -> Self {
+        // Intern the names, the valuable valves first
+        let mut order: Vec<&Valve> = valves.iter().collect();
+        order.sort_by_key(|v| (v.flow_rate == 0, v.name != "AA", v.name.as_str()));
+        let ids: HashMap<&str, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(id, v)| (v.name.as_str(), id))
+            .collect();
+
+        // Floyd–Warshall over every valve, the tunnels taking one minute each
+        let count = order.len();
+        let mut distances = vec![vec![u32::MAX; count]; count];
+        for (id, valve) in order.iter().enumerate() {
+            distances[id][id] = 0;
+            for tunnel in &valve.tunnels {
+                distances[id][ids[tunnel.as_str()]] = 1;
+            }
+        }
+        for k in 0..count {
+            for i in 0..count {
+                for j in 0..count {
+                    let through_k = distances[i][k].saturating_add(distances[k][j]);
+                    if through_k < distances[i][j] {
+                        distances[i][j] = through_k;
+                    }
+                }
+            }
+        }
+
+        // Only keep the valuable valves and the start
+        let valuable = order.iter().take_while(|v| v.flow_rate > 0).count();
+        let start = ids["AA"];
+        let kept = valuable.max(start + 1);
+
+        ValveSystem {
+            flow_rates: order[..kept].iter().map(|v| v.flow_rate).collect(),
+            distances: distances[..kept]
+                .iter()
+                .map(|row| row[..kept].to_vec())
+                .collect(),
+            valuable,
+            start,
+        }
+    }
+
+    /// # max_pressure
+    ///
+    /// Find the most pressure that can still be released, memoizing every state
+    ///
+    /// ## Arguments
+    ///
+    /// * `current` - The id of the current valve
+    /// * `time_left` - Remaining time in minutes
+    /// * `opened` - The mask of the opened valves
+    /// * `memo` - The best pressure of the states already explored
+    ///
+    /// ## Returns
+    /// * u32 - The maximum pressure released from now on
+    fn max_pressure(
+        &self,
+        current: usize,
+        time_left: u32,
+        opened: u64,
+        memo: &mut HashMap<(usize, u32, u64), u32>,
+    ) -> u32 {
+        if let Some(&pressure) = memo.get(&(current, time_left, opened)) {
+            return pressure;
+        }
+
+        let mut max_pressure = 0;
+
+        // Try opening each unopened valve
+        for valve in 0..self.valuable {
+            if opened & (1 << valve) != 0 {
+                continue;
+            }
+
+            // If we don't have time to reach and open the valve, skip it
+            let cost = self.distances[current][valve].saturating_add(1); // +1 for opening the valve
+            if cost >= time_left {
+                continue;
+            }
+
+            let new_time_left = time_left - cost;
+            let pressure = new_time_left * self.flow_rates[valve]
+                + self.max_pressure(valve, new_time_left, opened | (1 << valve), memo);
+            max_pressure = max_pressure.max(pressure);
+        }
+
+        memo.insert((current, time_left, opened), max_pressure);
+        max_pressure
+    }
+
+    /// # best_pressures
+    ///
+    /// Find the most pressure that can be released by opening each set of valves
+    ///
+    /// ## Arguments
+    ///
+    /// * `minutes` - The time available, starting from valve "AA"
+    ///
+    /// ## Returns
+    /// * HashMap<u64, u32> - The maximum pressure released for each mask of opened valves
+    fn best_pressures(&self, minutes: u32) -> HashMap<u64, u32> {
+        let mut best = HashMap::new();
+        self.dfs_best_pressures(self.start, minutes, 0, 0, &mut best);
+        best
+    }
+
+    /// # dfs_best_pressures
+    ///
+    /// Perform a depth-first search over the orders of opening the valves, keeping the best
+    /// pressure of every set of opened valves
+    ///
+    /// ## Arguments
+    ///
+    /// * `current` - The id of the current valve
+    /// * `time_left` - Remaining time in minutes
+    /// * `opened` - The mask of the opened valves
+    /// * `pressure` - Total pressure released by the opened valves until the end
+    /// * `best` - The best pressure found for each mask of opened valves
+    fn dfs_best_pressures(
+        &self,
+        current: usize,
+        time_left: u32,
+        opened: u64,
+        pressure: u32,
+        best: &mut HashMap<u64, u32>,
+    ) {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = (*entry).max(pressure);
+
+        // Try opening each unopened valve
+        for valve in 0..self.valuable {
+            if opened & (1 << valve) != 0 {
+                continue;
+            }
+
+            // If we don't have time to reach and open the valve, skip it
+            let cost = self.distances[current][valve].saturating_add(1); // +1 for opening the valve
+            if cost >= time_left {
+                continue;
+            }
+
+            let new_time_left = time_left - cost;
+            let new_pressure = pressure + new_time_left * self.flow_rates[valve];
+            self.dfs_best_pressures(
+                valve,
+                new_time_left,
+                opened | (1 << valve),
+                new_pressure,
+                best,
+            );
+        }
+    }
+
+    /// # find_optimal_path
+    ///
+    /// Find the optimal path to release the most pressure
+    ///
+    /// ## Returns
+    /// * u32 - The maximum pressure that can be released in 30 minutes
+    fn find_optimal_path(&self) -> u32 {
+        self.max_pressure(self.start, 30, 0, &mut HashMap::new())
+    }
+
+    /// # find_optimal_paths_with_elephant
+    ///
+    /// Find the most pressure released by opening valves with the help of an elephant.
+    /// Both work independently, so the best plan is made of the best pressures of two
+    /// disjoint sets of valves.
+    ///
+    /// ## Returns
+    /// * u32 - The maximum pressure that can be released in 26 minutes
+    fn find_optimal_paths_with_elephant(&self) -> u32 {
+        let mut best: Vec<(u64, u32)> = self.best_pressures(26).into_iter().collect();
+        best.sort_unstable_by_key(|&(_, pressure)| std::cmp::Reverse(pressure));
+
+        let mut max_pressure = 0;
+        for (i, &(mine, my_pressure)) in best.iter().enumerate() {
+            // The pressures are sorted, so no later pair can beat the best one
+            if my_pressure * 2 < max_pressure {
+                break;
+            }
+
+            for &(elephant, elephant_pressure) in &best[i..] {
+                if mine & elephant == 0 {
+                    max_pressure = max_pressure.max(my_pressure + elephant_pressure);
+                    // The next sets of the elephant release less pressure
+                    break;
+                }
+            }
+        }
+
+        max_pressure
+    }
+}
+
+// Functions  =========================================================================== Functions
+impl Solution for Day16 {
+    type Input = ValveSystem;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let valves: Vec<Valve> = parse::lines(input)?;
+        let names: HashSet<&str> = valves.iter().map(|valve| valve.name.as_str()).collect();
+
+        if !names.contains("AA") {
+            return Err(AocError::new("missing the starting valve AA"));
+        }
+
+        // Every tunnel must lead to a valve of the input
+        for (index, (line, valve)) in input.lines().zip(&valves).enumerate() {
+            if let Some(tunnel) = valve.tunnels.iter().find(|t| !names.contains(t.as_str())) {
+                return Err(AocError::new(format!("unknown valve {}", tunnel))
+                    .at_column(line.rfind(tunnel.as_str()).unwrap_or_default() + 1)
+                    .at_line(index + 1, line));
+            }
+        }
+
+        // The opened valves are stored as bits of a u64
+        if valves.iter().filter(|valve| valve.flow_rate > 0).count() > u64::BITS as usize {
+            return Err(AocError::new(format!(
+                "more than {} valves have a flow rate",
+                u64::BITS
+            )));
+        }
+
+        Ok(ValveSystem::new(valves))
+    }
+
+    fn part_one(system: &Self::Input) -> Answer {
+        // Maximum pressure that can be released
+        system.find_optimal_path().into()
+    }
+
+    fn part_two(system: &Self::Input) -> Answer {
+        // Maximum pressure released with the help of the elephant
+        system.find_optimal_paths_with_elephant().into()
+    }
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valve_from_str() {
+        let valve: Valve = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB"
+            .parse()
+            .unwrap();
+
+        assert_eq!(valve.name, "AA");
+        assert_eq!(valve.flow_rate, 0);
+        assert_eq!(
+            valve.tunnels,
+            vec!["DD".to_string(), "II".to_string(), "BB".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_valve_from_str_errors() {
+        let error = "Valve AA has flow rate=x; tunnel leads to valve DD"
+            .parse::<Valve>()
+            .unwrap_err();
+        assert_eq!(error.message(), "invalid number `x`");
+        assert_eq!(error.column(), Some(24));
+
+        let error = "Valve AA has flow rate=0; tunnels to DD"
+            .parse::<Valve>()
+            .unwrap_err();
+        assert_eq!(error.column(), Some(26));
+    }
+
+    #[test]
+    fn test_distances_between_valuable_valves() {
+        let system = Day16::parse(
+            "Valve AA has flow rate=0; tunnels lead to valves BB, DD\n\
+             Valve BB has flow rate=13; tunnels lead to valves AA, CC\n\
+             Valve CC has flow rate=0; tunnels lead to valves BB, DD\n\
+             Valve DD has flow rate=20; tunnels lead to valves AA, CC, EE\n\
+             Valve EE has flow rate=3; tunnel leads to valve DD",
+        )
+        .unwrap();
+
+        // BB, DD and EE are numbered first, then AA; CC is dropped
+        assert_eq!(system.flow_rates, vec![13, 20, 3, 0]);
+        assert_eq!(system.start, 3);
+        assert_eq!(system.distances[3], vec![1, 1, 2, 0]);
+        assert_eq!(system.distances[0][2], 3);
+    }
+
+    #[test]
+    fn test_parse_unknown_tunnel() {
+        let error = Day16::parse("Valve AA has flow rate=0; tunnel leads to valve BB")
+            .err()
+            .unwrap();
+
+        assert_eq!(error.message(), "unknown valve BB");
+        assert_eq!((error.line(), error.column()), (Some(1), Some(49)));
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! # day_01.rs
+//! Code for the day 01 of the Advent of Code challenge year 2023
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2023, 1, Day01);
+
+// Functions  =========================================================================== Functions
+const NAMED_NUMBERS: [&[u8]; 9] = [
+    b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
+];
+
+#[inline(always)]
+fn find_digit((idx, b): (usize, &u8), line: &[u8]) -> Option<u32> {
+    b.is_ascii_digit().then(|| u32::from(b - b'0')).or_else(|| {
+        NAMED_NUMBERS
+            .iter()
+            .position(|&name| line[idx..].starts_with(name))
+            .map(|i| i as u32 + 1)
+    })
+}
+
+impl Solution for Day01 {
+	type Input = Vec<String>;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		parse::lines_with(input, |line| {
+			let bytes = line.as_bytes();
+			if !bytes.iter().enumerate().any(|byte| find_digit(byte, bytes).is_some()) {
+				return Err(AocError::new("the line has no digit, written or spelled out"));
+			}
+
+			Ok(line.to_string())
+		})
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		// The lines with only spelled out digits, valid for the second part, leave it unsolved
+		input
+			.iter()
+			.map(|line| {
+				let mut bytes = line.bytes();
+				let first = bytes.find(u8::is_ascii_digit)?;
+				let last = bytes.rev().find(u8::is_ascii_digit).unwrap_or(first);
+				Some(u32::from((first - b'0') * 10 + (last - b'0')))
+			})
+			.sum::<Option<u32>>()
+			.into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		input
+			.iter()
+			.map(|l| l.as_bytes())
+			.map(|line| {
+				let mut bytes = line.iter().enumerate();
+				let first = bytes
+					.find_map(|byte| find_digit(byte, line))
+					.expect("checked by `parse`");
+				let last = bytes
+					.rev()
+					.find_map(|byte| find_digit(byte, line))
+					.unwrap_or(first);
+
+				first * 10 + last
+			})
+			.sum::<u32>()
+			.into()
+	}
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_lines_without_digit() {
+		let error = Day01::parse("1abc2\nabc").err().unwrap();
+		assert_eq!(error.message(), "the line has no digit, written or spelled out");
+		assert_eq!(error.line(), Some(2));
+
+		let input = Day01::parse("1abc2\nxtwone").unwrap();
+		assert_eq!(Day01::part_one(&input), Answer::Unsolved);
+		assert_eq!(Day01::part_two(&input), Answer::from(12 + 21));
+	}
+}
@@ -0,0 +1,121 @@
+//!
+//! # day_02.rs
+//! Code for the day 01 of the Advent of Code challenge year 2023
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse;
+use crate::scan;
+use crate::solution::{Answer, Solution};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2023, 2, Day02);
+
+///
+/// # Game
+///
+/// A game and the cubes shown in each of its sets, as `[red, green, blue]`.
+#[derive(Debug)]
+pub struct Game {
+	id: u32,
+	sets: Vec<[u32; 3]>,
+}
+
+// Functions  =========================================================================== Functions
+
+///
+/// # parse_game
+///
+/// ## Arguments
+///
+/// * `line` - A line like `Game 1: 3 blue, 4 red; 1 red, 2 green`
+///
+/// ## Returns
+///
+/// * `Result<Game, AocError>` - The game
+fn parse_game(line: &str) -> Result<Game, AocError> {
+	let (id, sets) = scan!(line, "Game {}: {}" => u32, &str)?;
+
+	let sets = sets
+		.split(';')
+		.map(|set| {
+			let mut cubes = [0u32; 3];
+
+			for cube_infos in set.split(',') {
+				let cube_infos = cube_infos.trim();
+				let (count, color) = scan!(cube_infos, "{} {}" => u32, &str)
+					.map_err(|error| error.within(line, cube_infos))?;
+				let index = match color {
+					"red" => 0,
+					"green" => 1,
+					"blue" => 2,
+					_ => return Err(AocError::new(format!("unknown color `{}`", color)).at_token(line, color)),
+				};
+				cubes[index] += count;
+			}
+
+			Ok(cubes)
+		})
+		.collect::<Result<_, _>>()?;
+
+	Ok(Game { id, sets })
+}
+
+impl Solution for Day02 {
+	type Input = Vec<Game>;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		parse::lines_with(input, parse_game)
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		let limits = [12, 13, 14];
+
+		let sum: u32 = input
+			.iter()
+			.filter(|game| {
+				game.sets
+					.iter()
+					.all(|set| set.iter().zip(limits.iter()).all(|(count, limit)| count <= limit))
+			})
+			.map(|game| game.id)
+			.sum();
+
+		sum.into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		let sum: u32 = input
+			.iter()
+			.map(|game| {
+				let mut max = [0u32; 3];
+				for set in &game.sets {
+					for (max, &count) in max.iter_mut().zip(set.iter()) {
+						*max = (*max).max(count);
+					}
+				}
+
+				max.iter().product::<u32>()
+			})
+			.sum();
+
+		sum.into()
+	}
+}
+
+// Tests ==================================================================================== Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_unknown_color() {
+		let error = Day02::parse("Game 1: 3 blue\nGame 2: 1 purple, 2 red").unwrap_err();
+
+		assert_eq!(error.message(), "unknown color `purple`");
+		assert_eq!(error.line(), Some(2));
+		assert_eq!(error.column(), Some(11));
+	}
+}
@@ -0,0 +1,144 @@
+//!
+//! # day_03.rs
+//! Code for the day 01 of the Advent of Code challenge year 2023
+//!
+
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2023, 3, Day03);
+
+///
+/// # Number
+/// A number of the schematic, written on a single row.
+#[derive(Debug, Clone)]
+struct Number {
+	value: u32,
+	start: Point<usize>,
+	length: usize,
+}
+
+///
+/// # Schematic
+/// The engine schematic, with the numbers found in it.
+pub struct Schematic {
+	grid: Grid<char>,
+	numbers: Vec<Number>,
+}
+
+// Functions  =========================================================================== Functions
+impl Number {
+	///
+	/// # adjacent_cells
+	/// Returns the cells around the number, diagonals included.
+	///
+	/// ## Arguments
+	/// * `self` - The number
+	/// * `grid` - The schematic
+	///
+	/// ## Returns
+	/// * `HashSet<Point<usize>>` - The positions next to one of the digits of the number
+	fn adjacent_cells(&self, grid: &Grid<char>) -> HashSet<Point<usize>> {
+		(0..self.length)
+			.flat_map(|offset| grid.neighbors8(Point::new(self.start.x + offset, self.start.y)))
+			.filter(|point| !grid[*point].is_ascii_digit())
+			.collect()
+	}
+}
+
+///
+/// # is_symbol
+/// Returns true if the character is a symbol, i.e. neither a digit nor a period.
+///
+/// ## Arguments
+/// * `c` - The character
+///
+/// ## Returns
+/// * `bool` - True if the character is a symbol
+fn is_symbol(c: char) -> bool {
+	!c.is_ascii_digit() && c != '.'
+}
+
+impl Solution for Day03 {
+	type Input = Schematic;
+
+	fn parse(input: &str) -> Result<Self::Input, AocError> {
+		let grid: Grid<char> = input.parse()?;
+		let mut numbers = Vec::new();
+
+		for (y, row) in grid.rows().enumerate() {
+			let mut x = 0;
+
+			while x < row.len() {
+				let length = row[x..]
+					.iter()
+					.take_while(|c| c.is_ascii_digit())
+					.count();
+
+				if length > 0 {
+					let digits: String = row[x..x + length].iter().collect();
+					let value = digits.parse::<u32>().map_err(|_| {
+						AocError::new(format!("invalid number `{}`", digits))
+							.at_column(x + 1)
+							.at_line(y + 1, &row.iter().collect::<String>())
+					})?;
+
+					numbers.push(Number {
+						value,
+						start: Point::new(x, y),
+						length,
+					});
+				}
+
+				x += length.max(1);
+			}
+		}
+
+		Ok(Schematic { grid, numbers })
+	}
+
+	fn part_one(input: &Self::Input) -> Answer {
+		let sum = input
+			.numbers
+			.iter()
+			.filter(|number| {
+				number
+					.adjacent_cells(&input.grid)
+					.iter()
+					.any(|&point| is_symbol(input.grid[point]))
+			})
+			.map(|number| number.value)
+			.sum::<u32>();
+
+		sum.into()
+	}
+
+	fn part_two(input: &Self::Input) -> Answer {
+		// the adjacent numbers of every gear, keyed by position
+		let mut gears_hm: HashMap<Point<usize>, Vec<u32>> = HashMap::new();
+
+		for number in input.numbers.iter() {
+			for point in number.adjacent_cells(&input.grid) {
+				if input.grid[point] == '*' {
+					gears_hm.entry(point).or_default().push(number.value);
+				}
+			}
+		}
+
+		let sum: u32 = gears_hm
+			.values()
+			.filter(|adjacent_numbers| {
+				adjacent_numbers.len() == 2 // only the gears with 2 adjacent numbers
+			})
+			.map(|adjacent_numbers| adjacent_numbers.iter().product::<u32>())
+			.sum();
+
+		sum.into()
+	}
+}
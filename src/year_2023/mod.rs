@@ -0,0 +1,8 @@
+//!
+//! # year_2023
+//! Solutions of the Advent of Code challenge year 2023
+//!
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
@@ -0,0 +1,79 @@
+//!
+//! # day_01.rs
+//! Code for the day 01 of the Advent of Code challenge year 2024
+//!
+// Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse::{self, FromInput};
+use crate::solution::{Answer, Solution};
+use std::{collections::HashMap, str::FromStr};
+
+// Variables  =========================================================================== Variables
+aoc_day!(2024, 1, Day01);
+
+#[derive(Debug)]
+pub struct Data {
+    left_values: Vec<i32>,
+    right_values: Vec<i32>,
+}
+
+/// A line of the input, like `15244   50562`.
+#[derive(FromInput)]
+#[input("{left} {right}")]
+struct Pair {
+    left: i32,
+    right: i32,
+}
+
+impl FromStr for Data {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pairs: Vec<Pair> = parse::lines(s)?;
+        let (left_values, right_values) = pairs.into_iter().map(|p| (p.left, p.right)).unzip();
+
+        Ok(Data {
+            left_values,
+            right_values,
+        })
+    }
+}
+// Functions  =========================================================================== Functions
+impl Solution for Day01 {
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input.parse()
+    }
+
+    fn part_one(data: &Self::Input) -> Answer {
+        let mut left_values = data.left_values.clone();
+        let mut right_values = data.right_values.clone();
+
+        left_values.sort();
+        right_values.sort();
+
+        left_values
+            .iter()
+            .zip(right_values.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part_two(data: &Self::Input) -> Answer {
+        let mut right_values_count: HashMap<i32, u32> = std::collections::HashMap::new();
+        for value in data.right_values.iter() {
+            *right_values_count.entry(*value).or_insert(0) += 1;
+        }
+
+        data.left_values
+            .iter()
+            .map(|value| *value as u32 * right_values_count.get(value).unwrap_or(&0))
+            .sum::<u32>()
+            .into()
+    }
+}
+
+// Tests ==================================================================================== Tests
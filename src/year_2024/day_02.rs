@@ -1,37 +1,42 @@
-///
-/// # day_02.rs
-/// Code for the day 02 of the Advent of Code challenge year 2024
-///
+//!
+//! # day_02.rs
+//! Code for the day 02 of the Advent of Code challenge year 2024
+//!
 // Imports  ==============================================================================  Imports
+use crate::aoc_day;
+use crate::error::AocError;
+use crate::parse::{self, FromInput};
+use crate::solution::{Answer, Solution};
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../../data/year_2024/inputs/day_02.txt");
+aoc_day!(2024, 2, Day02);
 
-struct Data {
+pub struct Data {
     levels: Vec<Vec<i32>>,
 }
 
+/// A line of the input, like `7 6 4 2 1`.
+#[derive(FromInput)]
+#[input("{levels}")]
+struct Report {
+    #[input(sep = " ")]
+    levels: Vec<i32>,
+}
+
 impl FromStr for Data {
-    type Err = ();
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut levels = Vec::new();
-
-        for line in s.lines() {
-            let values = line.split_whitespace();
-            let mut level = Vec::new();
-            for value in values {
-                level.push(value.parse().unwrap());
-            }
-            levels.push(level);
-        }
+        let reports: Vec<Report> = parse::lines(s)?;
 
-        Ok(Data { levels })
+        Ok(Data {
+            levels: reports.into_iter().map(|report| report.levels).collect(),
+        })
     }
 }
 
-fn is_always_increasing(levels: &Vec<i32>) -> bool {
+fn is_always_increasing(levels: &[i32]) -> bool {
     for i in 1..levels.len() {
         if levels[i] < levels[i - 1] {
             return false;
@@ -42,8 +47,8 @@ fn is_always_increasing(levels: &Vec<i32>) -> bool {
 }
 
 impl Data {
-    fn is_report_safe(&self, levels: &Vec<i32>) -> bool {
-        let mut levels = levels.clone();
+    fn is_report_safe(&self, levels: &[i32]) -> bool {
+        let mut levels = levels.to_vec();
 
         if levels.len() < 2 {
             return true;
@@ -51,7 +56,7 @@ impl Data {
 
         for i in 1..levels.len() {
             let diff = (levels[i] - levels[i - 1]).abs();
-            if diff < 1 || diff > 3 {
+            if !(1..=3).contains(&diff) {
                 return false;
             }
         }
@@ -65,13 +70,13 @@ impl Data {
         true
     }
 
-    fn is_report_safe_with_dampener(&self, levels: &Vec<i32>) -> bool {
+    fn is_report_safe_with_dampener(&self, levels: &[i32]) -> bool {
         if self.is_report_safe(levels) {
             return true;
         }
 
         for i in 0..levels.len() {
-            let mut modified_levels = levels.clone();
+            let mut modified_levels = levels.to_vec();
             modified_levels.remove(i);
             if self.is_report_safe(&modified_levels) {
                 return true;
@@ -97,22 +102,20 @@ impl Data {
 }
 // Functions  =========================================================================== Functions
 
-pub fn response_part_1() {
-    println!("Day 02 - Part 1");
-
-    let count = Data::from_str(INPUT).unwrap().count_safe_arrangements();
+impl Solution for Day02 {
+    type Input = Data;
 
-    println!("Count: {}", count);
-}
-
-pub fn response_part_2() {
-    println!("Day 02 - Part 2");
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Data::from_str(input)
+    }
 
-    let count = Data::from_str(INPUT)
-        .unwrap()
-        .count_safe_arrangements_with_dampener();
+    fn part_one(data: &Self::Input) -> Answer {
+        data.count_safe_arrangements().into()
+    }
 
-    println!("Count: {}", count);
+    fn part_two(data: &Self::Input) -> Answer {
+        data.count_safe_arrangements_with_dampener().into()
+    }
 }
 
 // Tests ==================================================================================== Tests
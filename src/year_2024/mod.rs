@@ -0,0 +1,7 @@
+//!
+//! # year_2024
+//! Solutions of the Advent of Code challenge year 2024
+//!
+
+pub mod day_01;
+pub mod day_02;
@@ -0,0 +1,131 @@
+//!
+//! # derive.rs
+//! The `FromStr` generated by `#[derive(FromInput)]`.
+//!
+
+// Imports  ==============================================================================  Imports
+use aoc::error::AocError;
+use aoc::parse::FromInput;
+
+// Variables  =========================================================================== Variables
+#[derive(Debug, PartialEq, FromInput)]
+#[input("{start}-{end}")]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, PartialEq, FromInput)]
+#[input("{} -> {}")]
+struct Segment(Range, char);
+
+#[derive(Debug, PartialEq, FromInput)]
+enum Operation {
+    #[input("noop")]
+    Noop,
+    #[input("addx {0}")]
+    Addx(i32),
+    #[input("move {count} to {stacks}")]
+    Move {
+        count: usize,
+        #[input(sep = ",")]
+        stacks: Vec<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, FromInput)]
+#[input("Monkey {id}: {items}")]
+struct Monkey {
+    id: usize,
+    #[input(sep = ", ")]
+    items: Vec<u64>,
+    #[input(default)]
+    inspections: u64,
+}
+
+#[derive(Debug, PartialEq, FromInput)]
+#[input("{left} {right}")]
+struct Pair<T> {
+    left: T,
+    right: T,
+}
+
+// Tests ==================================================================================== Tests
+#[test]
+fn test_derive_struct() {
+    assert_eq!("2-4".parse(), Ok(Range { start: 2, end: 4 }));
+    assert_eq!(
+        "6-8 -> x".parse(),
+        Ok(Segment(Range { start: 6, end: 8 }, 'x'))
+    );
+    assert_eq!(
+        "Monkey 3: 79, 98".parse(),
+        Ok(Monkey {
+            id: 3,
+            items: vec![79, 98],
+            inspections: 0
+        })
+    );
+
+    let error = "2-x".parse::<Range>().unwrap_err();
+    assert_eq!(error.message(), "invalid number `x`");
+    assert_eq!(error.column(), Some(3));
+
+    // Without the `-`, the first placeholder takes the whole text
+    let error = "2+4".parse::<Range>().unwrap_err();
+    assert_eq!(error.message(), "expected `-`");
+    assert_eq!(error.column(), Some(4));
+
+    assert_eq!(
+        "3 4".parse(),
+        Ok(Pair {
+            left: 3u8,
+            right: 4
+        })
+    );
+    assert_eq!(
+        "a b".parse(),
+        Ok(Pair {
+            left: "a".to_string(),
+            right: "b".to_string()
+        })
+    );
+    assert_eq!(
+        "1-2 3-4".parse(),
+        Ok(Pair {
+            left: Range { start: 1, end: 2 },
+            right: Range { start: 3, end: 4 }
+        })
+    );
+
+    // The errors of a nested type point into the whole text
+    let error = "6-y -> x".parse::<Segment>().unwrap_err();
+    assert_eq!(error.column(), Some(3));
+}
+
+#[test]
+fn test_derive_enum() {
+    assert_eq!("noop".parse(), Ok(Operation::Noop));
+    assert_eq!("addx -12".parse(), Ok(Operation::Addx(-12)));
+    assert_eq!(
+        "move 3 to 1,2".parse(),
+        Ok(Operation::Move {
+            count: 3,
+            stacks: vec![1, 2]
+        })
+    );
+
+    let error: AocError = "addx y".parse::<Operation>().unwrap_err();
+    assert_eq!(error.message(), "invalid number `y`");
+    assert_eq!(error.column(), Some(6));
+
+    let error = "move 3 to 1,z".parse::<Operation>().unwrap_err();
+    assert_eq!(error.column(), Some(13));
+
+    let error = "jump 3".parse::<Operation>().unwrap_err();
+    assert_eq!(
+        error.message(),
+        "expected `noop`, `addx {0}` or `move {count} to {stacks}`"
+    );
+    assert_eq!(error.column(), Some(1));
+}
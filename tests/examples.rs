@@ -0,0 +1,57 @@
+//!
+//! # examples.rs
+//! Runs every day against the examples of `data/year_XXXX/examples`.
+//! The tests are generated by `build.rs`, one per example file.
+//!
+
+// Imports  ==============================================================================  Imports
+use std::path::Path;
+
+use aoc::examples::Example;
+use aoc::registry;
+
+// Functions  =========================================================================== Functions
+///
+/// # check_example
+///
+/// ## Arguments
+///
+/// * `year` - The year of the puzzle
+/// * `day` - The day of the puzzle
+/// * `index` - The number of the example
+fn check_example(year: u16, day: u8, index: u8) {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let example = Example::load(&data_dir, year, day, index).unwrap();
+    let puzzle = registry::find(year, day)
+        .unwrap_or_else(|| panic!("{} day {} has examples but isn't registered", year, day));
+
+    // An example created by `aoc new` and not filled in yet
+    if example.input.is_empty() && example.expected.iter().all(Option::is_none) {
+        return;
+    }
+
+    assert!(
+        example.expected.iter().any(Option::is_some),
+        "{} has no expected answer",
+        Example::path(&data_dir, year, day, index).display()
+    );
+
+    let mismatches = example
+        .check(puzzle)
+        .unwrap_or_else(|error| panic!("{} day {} example {}: {}", year, day, index, error));
+    assert!(
+        mismatches.is_empty(),
+        "{} day {} example {}:\n{}",
+        year,
+        day,
+        index,
+        mismatches
+            .iter()
+            .map(|mismatch| format!("  {}", mismatch))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+// Tests ==================================================================================== Tests
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
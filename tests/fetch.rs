@@ -0,0 +1,87 @@
+//!
+//! # fetch.rs
+//! Downloads of the inputs, against a local stand-in of the website.
+//!
+
+mod common;
+
+// Imports  ==============================================================================  Imports
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::client::{self, Client, ClientConfig, ClientError};
+use aoc::input::InputLoader;
+use common::StandIn;
+
+// Functions  =========================================================================== Functions
+fn data_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn client(server: &StandIn, session: Option<&str>) -> Client {
+    Client::new(ClientConfig {
+        base_url: server.base_url.clone(),
+        session: session.map(String::from),
+        contact: Some("tests".to_string()),
+    })
+}
+
+// Tests ==================================================================================== Tests
+#[test]
+fn test_fetch_downloads_once() {
+    let server = StandIn::start(|request| match request.path.as_str() {
+        "/2022/day/1/input" => (200, "1000\n2000\n".to_string()),
+        _ => (404, "Not found".to_string()),
+    });
+    let dir = data_dir("once");
+    let loader = InputLoader::new(&dir);
+    let client = client(&server, Some("secret"));
+
+    let (path, downloaded) = client::fetch_input(&client, &loader, 2022, 1).unwrap();
+    let (_, downloaded_again) = client::fetch_input(&client, &loader, 2022, 1).unwrap();
+    let input = loader.load(2022, 1).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(downloaded);
+    assert!(!downloaded_again);
+    assert_eq!(path, dir.join("year_2022/inputs/day_01.txt"));
+    assert_eq!(input, "1000\n2000\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    assert_eq!(
+        requests[0].header("user-agent"),
+        Some(client::user_agent(Some("tests")).as_str())
+    );
+}
+
+#[test]
+fn test_fetch_errors_are_not_cached() {
+    let server = StandIn::start(|_| (400, "Please log in".to_string()));
+    let dir = data_dir("errors");
+    let loader = InputLoader::new(&dir);
+
+    let error = client::fetch_input(&client(&server, Some("expired")), &loader, 2022, 2);
+    let cached = loader.path(2022, 2).exists();
+
+    assert!(matches!(
+        error,
+        Err(ClientError::Status { status: 400, .. })
+    ));
+    assert!(!cached);
+}
+
+#[test]
+fn test_fetch_without_session() {
+    let server = StandIn::start(|_| (200, String::new()));
+    let loader = InputLoader::new(data_dir("session"));
+
+    let error = client::fetch_input(&client(&server, None), &loader, 2022, 3);
+
+    assert!(matches!(error, Err(ClientError::MissingSession)));
+    assert!(server.requests().is_empty());
+}
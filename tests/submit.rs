@@ -0,0 +1,145 @@
+//!
+//! # submit.rs
+//! Submissions of the answers, against a local stand-in of the website.
+//!
+
+mod common;
+
+// Imports  ==============================================================================  Imports
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::client::{Client, ClientConfig};
+use aoc::solution::{Answer, Part};
+use aoc::submit::{self, Feedback, GuessHistory, Refusal, SubmitError};
+use common::StandIn;
+
+// Functions  =========================================================================== Functions
+fn history(name: &str) -> (GuessHistory, PathBuf) {
+    let path =
+        std::env::temp_dir().join(format!("aoc_submit_{}_{}.toml", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    (GuessHistory::load(&path).unwrap(), path)
+}
+
+fn client(server: &StandIn) -> Client {
+    Client::new(ClientConfig {
+        base_url: server.base_url.clone(),
+        session: Some("secret".to_string()),
+        contact: None,
+    })
+}
+
+fn page(text: &str) -> String {
+    format!("<main><article><p>{}</p></article></main>", text)
+}
+
+// Tests ==================================================================================== Tests
+#[test]
+fn test_submit_sends_the_form() {
+    let server = StandIn::start(|_| (200, page("That's the right answer!")));
+    let (mut history, path) = history("form");
+
+    let feedback = submit::submit(
+        &client(&server),
+        &mut history,
+        2022,
+        1,
+        Part::One,
+        &Answer::Number(42),
+    );
+    let saved = GuessHistory::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(feedback.unwrap(), Feedback::Correct);
+    assert_eq!(saved.guesses(2022, 1, Part::One).count(), 1);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=1&answer=42");
+}
+
+#[test]
+fn test_submit_refuses_known_wrong_answers() {
+    let server = StandIn::start(|_| {
+        (
+            200,
+            page("That's not the right answer; your answer is too high."),
+        )
+    });
+    let (mut history, path) = history("bounds");
+    let client = client(&server);
+
+    let first = submit::submit(
+        &client,
+        &mut history,
+        2022,
+        2,
+        Part::Two,
+        &Answer::Number(500),
+    );
+    let again = submit::submit(
+        &client,
+        &mut history,
+        2022,
+        2,
+        Part::Two,
+        &Answer::Number(500),
+    );
+    let higher = submit::submit(
+        &client,
+        &mut history,
+        2022,
+        2,
+        Part::Two,
+        &Answer::Number(600),
+    );
+    let reloaded = GuessHistory::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(first.unwrap(), Feedback::TooHigh);
+    assert!(matches!(
+        again,
+        Err(SubmitError::Refused(Refusal::AlreadyGuessed {
+            feedback: Feedback::TooHigh
+        }))
+    ));
+    assert!(matches!(
+        higher,
+        Err(SubmitError::Refused(Refusal::AboveTooHigh { .. }))
+    ));
+    assert_eq!(
+        reloaded.check(2022, 2, Part::Two, &Answer::Number(501)),
+        Err(Refusal::AboveTooHigh {
+            bound: "500".to_string()
+        })
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_submit_does_not_record_waits() {
+    let server = StandIn::start(|_| {
+        (
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait."),
+        )
+    });
+    let (mut history, path) = history("wait");
+
+    let feedback = submit::submit(
+        &client(&server),
+        &mut history,
+        2022,
+        3,
+        Part::One,
+        &Answer::Text("CMZ".to_string()),
+    );
+
+    assert_eq!(feedback.unwrap(), Feedback::Wait { seconds: 45 });
+    assert_eq!(history.guesses(2022, 3, Part::One).count(), 0);
+    assert!(!path.exists());
+}